@@ -1,26 +1,70 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use jp_deinflector::deinflect;
+use jp_deinflector::{deinflect, Deinflector};
+
+const WORDS: [(&str, &str); 3] = [
+    ("single inflection", "食べた"),
+    ("normal number of inflections", "言ってなかった"),
+    ("many inflections", "食べさせられたくなかった"),
+];
+
+/// A sentence that is scanned at every character position, like a hover lookup does
+const SENTENCE: &str =
+    "昨日は友達と一緒に映画を見に行ったけど、あまり面白くなかったので途中で帰ってしまいました";
 
 fn benchmark_deinflect(c: &mut Criterion) {
     let mut group = c.benchmark_group("deinflection");
 
-    // Simple case
-    group.bench_function("single inflection", |b| {
-        b.iter(|| deinflect(black_box("食べた")))
-    });
+    for (name, word) in WORDS {
+        group.bench_function(name, |b| b.iter(|| deinflect(black_box(word))));
+    }
+
+    group.finish();
+}
+
+fn benchmark_deinflect_into(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deinflection with reused buffers");
+
+    for (name, word) in WORDS {
+        let mut deinflector = Deinflector::new();
+        let mut out = Vec::new();
+        group.bench_function(name, |b| {
+            b.iter(|| deinflector.deinflect_into(black_box(word), &mut out))
+        });
+    }
+
+    group.finish();
+}
+
+fn benchmark_scanning(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scanning");
+    let positions: Vec<usize> = SENTENCE.char_indices().map(|(i, _)| i).collect();
 
-    // Normal case
-    group.bench_function("normal number of inflections", |b| {
-        b.iter(|| deinflect(black_box("言ってなかった")))
+    group.bench_function("deinflect", |b| {
+        b.iter(|| {
+            for &i in &positions {
+                black_box(deinflect(black_box(&SENTENCE[i..])));
+            }
+        })
     });
 
-    // Complex case
-    group.bench_function("many inflections", |b| {
-        b.iter(|| deinflect(black_box("食べさせられたくなかった")))
+    let mut deinflector = Deinflector::new();
+    let mut out = Vec::new();
+    group.bench_function("deinflect_into", |b| {
+        b.iter(|| {
+            for &i in &positions {
+                deinflector.deinflect_into(black_box(&SENTENCE[i..]), &mut out);
+                black_box(&out);
+            }
+        })
     });
 
     group.finish();
 }
 
-criterion_group!(benches, benchmark_deinflect);
+criterion_group!(
+    benches,
+    benchmark_deinflect,
+    benchmark_deinflect_into,
+    benchmark_scanning
+);
 criterion_main!(benches);
@@ -0,0 +1,100 @@
+#ifndef JP_DEINFLECTOR_H
+#define JP_DEINFLECTOR_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The deinflections of a word, see [`jp_deinflect`]
+ */
+typedef struct JpDeinflections JpDeinflections;
+
+/**
+ * Deinflects `word`, see [`deinflect_detailed`]
+ *
+ * # Safety
+ * `word` must be null or a null-terminated string.
+ */
+struct JpDeinflections *jp_deinflect(const char *word);
+
+/**
+ * Frees a result list returned by [`jp_deinflect`]
+ *
+ * # Safety
+ * `list` must be null or a list returned by [`jp_deinflect`] that hasn't been freed yet.
+ */
+void jp_deinflections_free(struct JpDeinflections *list);
+
+/**
+ * Returns the number of candidates in `list`
+ *
+ * # Safety
+ * `list` must be null or a list returned by [`jp_deinflect`] that hasn't been freed yet.
+ */
+size_t jp_deinflections_len(const struct JpDeinflections *list);
+
+/**
+ * Returns the deinflected word of the candidate at `index`
+ *
+ * # Safety
+ * `list` must be null or a list returned by [`jp_deinflect`] that hasn't been freed yet.
+ */
+char *jp_deinflections_word(const struct JpDeinflections *list, size_t index);
+
+/**
+ * Returns the number of rule types of the candidate at `index`
+ *
+ * # Safety
+ * `list` must be null or a list returned by [`jp_deinflect`] that hasn't been freed yet.
+ */
+size_t jp_deinflections_type_count(const struct JpDeinflections *list, size_t index);
+
+/**
+ * Returns the name of a rule type of the candidate at `index`, e.g. "V5T"
+ *
+ * # Safety
+ * `list` must be null or a list returned by [`jp_deinflect`] that hasn't been freed yet.
+ */
+char *jp_deinflections_type(const struct JpDeinflections *list, size_t index, size_t type_index);
+
+/**
+ * Returns the number of inflections that lead to the candidate at `index`
+ *
+ * # Safety
+ * `list` must be null or a list returned by [`jp_deinflect`] that hasn't been freed yet.
+ */
+size_t jp_deinflections_inflection_count(const struct JpDeinflections *list, size_t index);
+
+/**
+ * Returns the English name of an inflection of the candidate at `index`, e.g. "past".
+ * The inflections are ordered from the base form outwards.
+ *
+ * # Safety
+ * `list` must be null or a list returned by [`jp_deinflect`] that hasn't been freed yet.
+ */
+char *jp_deinflections_inflection(const struct JpDeinflections *list,
+                                  size_t index,
+                                  size_t inflection_index);
+
+/**
+ * Converts the katakana in `kata` to hiragana, see [`kata_to_hira`](crate::kata_to_hira)
+ *
+ * # Safety
+ * `kata` must be null or a null-terminated string.
+ */
+char *jp_kata_to_hira(const char *kata);
+
+/**
+ * Frees a string returned by this API
+ *
+ * # Safety
+ * `s` must be null or a string returned by this API that hasn't been freed yet.
+ */
+void jp_string_free(char *s);
+
+#endif  /* JP_DEINFLECTOR_H */
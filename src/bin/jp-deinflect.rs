@@ -0,0 +1,282 @@
+//! Prints the deinflections of the words given as arguments or, without arguments, of every
+//! line of stdin.
+//!
+//! ```text
+//! jp-deinflect [--json | --tsv] [--words FILE] [WORD...]
+//! ```
+
+use jp_deinflector::{deinflect_detailed, deinflect_with_lexicon, kata_to_hira};
+use jp_deinflector::{Deinflection, RuleType};
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+use std::{env, fs};
+
+const USAGE: &str = "\
+Usage: jp-deinflect [--json | --tsv] [--words FILE] [WORD...]
+
+Prints the possible base forms of each WORD, or of every line of stdin if no WORD is given.
+
+Options:
+  --json          Print one JSON object per input word
+  --tsv           Print one tab-separated line per candidate: input, base form, types, inflections
+  --words FILE    Only print base forms listed in FILE, one word per line, optionally followed
+                  by a tab and comma-separated JMdict part-of-speech tags (e.g. 待つ<TAB>v5t)
+  -h, --help      Print this help
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+    Tsv,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    format: Format,
+    word_list: Option<String>,
+    words: Vec<String>,
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut parsed = Args {
+        format: Format::Text,
+        word_list: None,
+        words: Vec::new(),
+    };
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--json" => parsed.format = Format::Json,
+            "--tsv" => parsed.format = Format::Tsv,
+            "--words" => {
+                let file = args.next().ok_or("--words needs a file name")?;
+                parsed.word_list = Some(file);
+            }
+            "--" => parsed.words.extend(args.by_ref()),
+            _ if arg.starts_with('-') => return Err(format!("Unknown option '{}'", arg)),
+            _ => parsed.words.push(arg),
+        }
+    }
+    Ok(Some(parsed))
+}
+
+/// Reads a word list with one word per line and optional part-of-speech tags after a tab.
+/// Words without (known) tags may have any type.
+fn parse_word_list(contents: &str) -> HashMap<String, Vec<RuleType>> {
+    let mut lexicon: HashMap<String, Vec<RuleType>> = HashMap::new();
+    for line in contents.lines() {
+        let mut columns = line.split('\t');
+        let word = columns.next().unwrap_or_default().trim();
+        if word.is_empty() {
+            continue;
+        }
+        let mut types: Vec<RuleType> = columns
+            .next()
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|tag| RuleType::from_pos_tag(tag.trim()))
+            .collect();
+        if types.is_empty() {
+            types = RuleType::ALL.to_vec();
+        }
+        // The candidates are always written in hiragana
+        let types_of_word = lexicon.entry(kata_to_hira(word)).or_default();
+        for t in types {
+            if !types_of_word.contains(&t) {
+                types_of_word.push(t);
+            }
+        }
+    }
+    lexicon
+}
+
+fn type_names(deinflection: &Deinflection) -> Vec<String> {
+    deinflection
+        .types
+        .iter()
+        .map(|t| format!("{:?}", t))
+        .collect()
+}
+
+fn inflection_names(deinflection: &Deinflection) -> Vec<&'static str> {
+    deinflection
+        .inflections()
+        .map(|k| k.english_name())
+        .collect()
+}
+
+fn write_deinflections(
+    out: &mut impl Write,
+    format: Format,
+    input: &str,
+    deinflections: &[Deinflection],
+) -> io::Result<()> {
+    match format {
+        Format::Text => {
+            writeln!(out, "{}", input)?;
+            for deinflection in deinflections {
+                writeln!(
+                    out,
+                    "  {}\t[{}]\t{}",
+                    deinflection.word,
+                    type_names(deinflection).join(", "),
+                    inflection_names(deinflection).join(" → ")
+                )?;
+            }
+        }
+        Format::Tsv => {
+            for deinflection in deinflections {
+                writeln!(
+                    out,
+                    "{}\t{}\t{}\t{}",
+                    input,
+                    deinflection.word,
+                    type_names(deinflection).join(","),
+                    inflection_names(deinflection).join(",")
+                )?;
+            }
+        }
+        Format::Json => {
+            let candidates: Vec<_> = deinflections
+                .iter()
+                .map(|deinflection| {
+                    serde_json::json!({
+                        "word": deinflection.word,
+                        "types": type_names(deinflection),
+                        "inflections": inflection_names(deinflection),
+                    })
+                })
+                .collect();
+            let line = serde_json::json!({ "input": input, "candidates": candidates });
+            writeln!(out, "{}", line)?;
+        }
+    }
+    Ok(())
+}
+
+fn run(args: Args) -> io::Result<()> {
+    let lexicon = match &args.word_list {
+        Some(file) => Some(parse_word_list(&fs::read_to_string(file)?)),
+        None => None,
+    };
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut process = |word: &str| -> io::Result<()> {
+        let deinflections = match &lexicon {
+            Some(lexicon) => deinflect_with_lexicon(word, lexicon),
+            None => deinflect_detailed(word),
+        };
+        write_deinflections(&mut out, args.format, word, &deinflections)?;
+        // Show the result right away when used interactively
+        out.flush()
+    };
+
+    if args.words.is_empty() {
+        for line in io::stdin().lock().lines() {
+            let line = line?;
+            let word = line.trim();
+            if !word.is_empty() {
+                process(word)?;
+            }
+        }
+    } else {
+        for word in &args.words {
+            process(word)?;
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            print!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        // E.g. when piped into head
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("jp-deinflect: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Option<Args>, String> {
+        parse_args(args.iter().map(|s| s.to_string()))
+    }
+
+    fn output(format: Format, input: &str) -> String {
+        let mut out = Vec::new();
+        write_deinflections(&mut out, format, input, &deinflect_detailed(input)).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let parsed = args(&["--tsv", "待った", "--words", "words.txt", "--", "--json"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            parsed,
+            Args {
+                format: Format::Tsv,
+                word_list: Some("words.txt".to_string()),
+                words: vec!["待った".to_string(), "--json".to_string()],
+            }
+        );
+        assert_eq!(args(&["--help"]), Ok(None));
+        assert!(args(&["--words"]).is_err());
+        assert!(args(&["--xml"]).is_err());
+    }
+
+    #[test]
+    fn test_word_list() {
+        let lexicon = parse_word_list("待つ\tv5t\nサボる\n\n待る\tadj-i\n");
+        assert_eq!(lexicon["待つ"], vec![RuleType::V5T]);
+        assert_eq!(lexicon["さぼる"], RuleType::ALL);
+        let words: Vec<_> = deinflect_with_lexicon("待った", &lexicon)
+            .into_iter()
+            .map(|d| d.word)
+            .collect();
+        assert_eq!(words, vec!["待つ"]);
+    }
+
+    #[test]
+    fn test_formats() {
+        let text = output(Format::Text, "待った");
+        assert!(text.starts_with("待った\n"));
+        assert!(text.contains("  待つ\t[V5T]\tpast\n"));
+
+        let tsv = output(Format::Tsv, "待った");
+        assert!(tsv.lines().any(|line| line == "待った\t待つ\tV5T\tpast"));
+
+        let json = output(Format::Json, "待った");
+        assert_eq!(json.lines().count(), 1);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["input"], "待った");
+        assert!(value["candidates"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!({
+                "word": "待つ",
+                "types": ["V5T"],
+                "inflections": ["past"],
+            })));
+    }
+}
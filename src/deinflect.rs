@@ -1,36 +1,228 @@
-use crate::deinflection_rules::{get_deinflection_rules, MAX_SUFFIX_LENGTH};
-use crate::kata_to_hira::kata_to_hira;
+use crate::inflection_kind::InflectionKind;
+use crate::kata_to_hira::{hira_to_kata, is_hiragana, is_katakana, kata_to_hira, romaji_to_hira};
+use crate::normalize::{expand_iteration_marks, fold_width, normalized_spellings};
+use crate::rank::is_kanji;
+use crate::rule_set::{Rules, BUILTIN_RULES};
 use fxhash::FxHashSet;
+use std::ops::Range;
 
 #[inline]
-fn concatenate(a: &str, b: &str) -> String {
+pub(crate) fn concatenate(a: &str, b: &str) -> String {
     let mut str = String::with_capacity(a.len() + b.len());
     str.push_str(a);
     str.push_str(b);
     str
 }
 
+/// The prefixes that honorific and humble forms put in front of the word, e.g. お読みになる
+pub(crate) const HONORIFIC_PREFIXES: &[&str] = &["お", "ご", "御"];
+
+/// The kana that the masu stems of ichidan verbs end in, e.g. 起き or 見せ.
+/// Stems that consist of kanji only, like 見, are allowed as well.
+const ICHIDAN_STEM_ENDINGS: &str = "いきぎじちびみりえけげせぜてでねへべぺめれ";
+
+/// Returns true if `stem`, which followed the honorific prefix, can be the masu stem of one of
+/// `types`. This keeps the prefix from being stripped off nouns like in ご飯にする.
+pub(crate) fn is_honorific_stem(types: &[RuleType], stem: &str) -> bool {
+    let Some(last) = stem.chars().last() else {
+        return false;
+    };
+    types.iter().any(|rule_type| match rule_type {
+        // Nouns like 説明 or 電話, which are usually written in kanji or katakana
+        RuleType::Vs => !is_hiragana(last),
+        RuleType::V1 => is_kanji(last) || ICHIDAN_STEM_ENDINGS.contains(last),
+        // The only ぬ verb, 死ぬ, has no honorific or humble forms with the prefix
+        RuleType::V5N => false,
+        // The masu stem ending of godan verbs is part of the rule's suffix, e.g. きになる
+        _ => true,
+    })
+}
+
+/// Returns `stem` without the honorific prefix that `rule` requires, `None` if the prefix is
+/// missing or the rest of the stem can't be a masu stem of the rule's types
+#[inline]
+pub(crate) fn strip_honorific_prefix<'s>(
+    rule: &DeinflectionRule,
+    stem: &'s str,
+) -> Option<&'s str> {
+    if !rule.kind.has_honorific_prefix() {
+        return Some(stem);
+    }
+    HONORIFIC_PREFIXES
+        .iter()
+        .find_map(|prefix| stem.strip_prefix(prefix))
+        .filter(|stem| is_honorific_stem(rule.rules_out, stem))
+}
+
 /// The grammatical type of the word, which determines
 /// the rules that can be applied for deinflection
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, hash, frozen, module = "jp_deinflector")
+)]
 pub enum RuleType {
     AdjI,
+    /// Na-adjectives, whose base form is the stem without the copula, e.g. 静か
+    AdjNa,
+    /// The copula だ, which na-adjectives are conjugated with
+    Copula,
     Iru,
     V1,
-    V5,
+    /// Godan verbs ending in く, e.g. 書く
+    V5K,
+    /// The godan verb 行く and its compounds, whose te-form is 行って
+    V5KS,
+    /// Godan verbs ending in ぐ, e.g. 泳ぐ
+    V5G,
+    /// Godan verbs ending in す, e.g. 話す
+    V5S,
+    /// Godan verbs ending in つ, e.g. 待つ
+    V5T,
+    /// Godan verbs ending in ぬ, e.g. 死ぬ
+    V5N,
+    /// Godan verbs ending in ぶ, e.g. 遊ぶ
+    V5B,
+    /// Godan verbs ending in む, e.g. 読む
+    V5M,
+    /// Godan verbs ending in る, e.g. 売る
+    V5R,
+    /// The godan verb ある (v5r-i). Its irregular negative ない isn't deinflected to ある, since
+    /// a rule for it would match every negative.
+    V5RI,
+    /// Honorific godan verbs like くださる, whose masu stem and imperative are ください
+    V5Aru,
+    /// Godan verbs ending in う, e.g. 買う
+    V5U,
+    /// Godan verbs ending in う with a special te-form, e.g. 問う -> 問うて
+    V5US,
     Vk,
     Vs,
     Vz,
+    /// Classical kami nidan verbs, e.g. 起く (起きず, 起くる)
+    V2K,
+    /// Classical shimo nidan verbs, e.g. 受く (受けず, 受くる)
+    V2S,
+    /// Classical nahen verbs, e.g. 死ぬ (死なず, 死ぬる)
+    Vn,
+    /// Classical rahen verbs and the auxiliaries that conjugate like them, e.g. あり or たり
+    Vr,
+    /// Classical ku adjectives, e.g. 高し (高き)
+    AdjKu,
+    /// Classical shiku adjectives, e.g. 美し (美しき)
+    AdjShiku,
 }
 
-#[derive(Debug, Clone)]
-pub struct DeinflectionRule {
-    pub kana_out: &'static str,
-    pub rules_in: &'static [RuleType],
-    pub rules_out: &'static [RuleType],
+impl RuleType {
+    /// All rule types in declaration order
+    pub const ALL: &'static [RuleType] = &[
+        RuleType::AdjI,
+        RuleType::AdjNa,
+        RuleType::Copula,
+        RuleType::Iru,
+        RuleType::V1,
+        RuleType::V5K,
+        RuleType::V5KS,
+        RuleType::V5G,
+        RuleType::V5S,
+        RuleType::V5T,
+        RuleType::V5N,
+        RuleType::V5B,
+        RuleType::V5M,
+        RuleType::V5R,
+        RuleType::V5RI,
+        RuleType::V5Aru,
+        RuleType::V5U,
+        RuleType::V5US,
+        RuleType::Vk,
+        RuleType::Vs,
+        RuleType::Vz,
+        RuleType::V2K,
+        RuleType::V2S,
+        RuleType::Vn,
+        RuleType::Vr,
+        RuleType::AdjKu,
+        RuleType::AdjShiku,
+    ];
+
+    /// Converts a JMdict part-of-speech tag (e.g. "v5t" or "adj-i") into the corresponding type.
+    /// Returns `None` for tags of words that don't inflect according to any of the types.
+    ///
+    /// # Examples
+    /// ```
+    /// use jp_deinflector::RuleType;
+    /// assert_eq!(RuleType::from_pos_tag("v5t"), Some(RuleType::V5T));
+    /// assert_eq!(RuleType::from_pos_tag("n"), None);
+    /// ```
+    pub fn from_pos_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "adj-i" | "adj-ix" => Some(RuleType::AdjI),
+            "adj-na" => Some(RuleType::AdjNa),
+            "cop" | "cop-da" => Some(RuleType::Copula),
+            "v1" | "v1-s" => Some(RuleType::V1),
+            "vk" => Some(RuleType::Vk),
+            "vs-i" | "vs-s" => Some(RuleType::Vs),
+            "vz" => Some(RuleType::Vz),
+            "v5k" => Some(RuleType::V5K),
+            "v5k-s" => Some(RuleType::V5KS),
+            "v5g" => Some(RuleType::V5G),
+            "v5s" => Some(RuleType::V5S),
+            "v5t" => Some(RuleType::V5T),
+            "v5n" => Some(RuleType::V5N),
+            "v5b" => Some(RuleType::V5B),
+            "v5m" => Some(RuleType::V5M),
+            "v5r" => Some(RuleType::V5R),
+            "v5r-i" => Some(RuleType::V5RI),
+            "v5aru" => Some(RuleType::V5Aru),
+            "v5u" => Some(RuleType::V5U),
+            "v5u-s" => Some(RuleType::V5US),
+            "vn" => Some(RuleType::Vn),
+            "vr" => Some(RuleType::Vr),
+            "adj-ku" => Some(RuleType::AdjKu),
+            "adj-shiku" => Some(RuleType::AdjShiku),
+            // JMdict distinguishes nidan verbs by their row, e.g. v2k-k or v2r-s
+            _ if tag.starts_with("v2") && tag.ends_with("-k") => Some(RuleType::V2K),
+            _ if tag.starts_with("v2") && tag.ends_with("-s") => Some(RuleType::V2S),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the type is one of the godan verb classes
+    pub fn is_godan(self) -> bool {
+        matches!(
+            self,
+            RuleType::V5K
+                | RuleType::V5KS
+                | RuleType::V5G
+                | RuleType::V5S
+                | RuleType::V5T
+                | RuleType::V5N
+                | RuleType::V5B
+                | RuleType::V5M
+                | RuleType::V5R
+                | RuleType::V5RI
+                | RuleType::V5Aru
+                | RuleType::V5U
+                | RuleType::V5US
+        )
+    }
+}
+
+/// A rule that replaces an inflected suffix by `kana_out`.
+/// The rules of the built-in table borrow `'static` data, the ones of a
+/// [`RuleSet`](crate::RuleSet) borrow from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DeinflectionRule<'a> {
+    pub kana_out: &'a str,
+    pub rules_in: &'a [RuleType],
+    pub rules_out: &'a [RuleType],
+    /// The inflection that this rule undoes
+    pub kind: InflectionKind,
 }
 
-impl DeinflectionRule {
+impl DeinflectionRule<'_> {
     fn can_apply_to(&self, word: &DeinflectedWord) -> bool {
         // Case: don't know what type the word might have -> Can apply all rules
         word.get_types().is_empty()
@@ -43,7 +235,11 @@ impl DeinflectionRule {
         if self.can_apply_to(deinflected_word) {
             let word = deinflected_word.get_word();
             debug_assert!(word.len() >= suffix_len);
-            let stem = &word[..word.len() - suffix_len];
+            let stem = strip_honorific_prefix(self, &word[..word.len() - suffix_len])?;
+            if stem.is_empty() && self.kana_out.is_empty() {
+                // E.g. だ -> "" for the na-adjective stem
+                return None;
+            }
             Some(concatenate(stem, self.kana_out))
         } else {
             None
@@ -52,21 +248,34 @@ impl DeinflectionRule {
 }
 
 #[derive(Debug)]
-pub struct DeinflectedWord {
+pub struct DeinflectedWord<'a> {
     pub word: String,
-    pub types: &'static [RuleType],
+    pub types: &'a [RuleType],
+    /// The rule that produced this word, `None` for the initial input
+    pub rule: Option<DeinflectionRule<'a>>,
+    /// Index of the word this one was deinflected from in the list of deinflections,
+    /// `None` if it was deinflected directly from the input
+    pub parent: Option<usize>,
+    /// Length of the prefix that is still unchanged from the input
+    pub stem_len: usize,
 }
 
-impl DeinflectedWord {
-    pub fn new(word: String, types: &'static [RuleType]) -> Self {
-        Self { word, types }
+impl<'a> DeinflectedWord<'a> {
+    pub fn new(word: String, types: &'a [RuleType]) -> Self {
+        Self {
+            stem_len: word.len(),
+            word,
+            types,
+            rule: None,
+            parent: None,
+        }
     }
 
     pub fn get_word(&self) -> &str {
         &self.word
     }
 
-    pub fn get_types(&self) -> &'static [RuleType] {
+    pub fn get_types(&self) -> &'a [RuleType] {
         self.types
     }
 }
@@ -90,31 +299,269 @@ impl SeenWordsTracker {
     }
 }
 
-/// Returns an iterator over all suffixes of length <= MAX_SUFFIX_LENGTH of the word.
-/// Assumes that the word only consists of Japanese characters.
-/// (Otherwise there is nothing to deflect anyway, so it doesn't matter if this function breaks)
-fn capped_suffixes(word: &str) -> impl Iterator<Item = &str> {
-    let max_suffix_bytes = MAX_SUFFIX_LENGTH * 3; // each jap character is 3 bytes
-    let start_pos = word.len().saturating_sub(max_suffix_bytes);
-    (start_pos..word.len()).step_by(3).map(|i| &word[i..])
+/// Returns an iterator over all non-empty suffixes of the word that are at most
+/// `max_suffix_length` characters long, from the longest to the shortest.
+/// Works on characters of any UTF-8 length, e.g. ASCII, half-width katakana or 𠮟.
+pub(crate) fn capped_suffixes(word: &str, max_suffix_length: usize) -> impl Iterator<Item = &str> {
+    let start_pos = word
+        .char_indices()
+        .rev()
+        .take(max_suffix_length)
+        .last()
+        .map_or(word.len(), |(i, _)| i);
+    word[start_pos..]
+        .char_indices()
+        .map(move |(i, _)| &word[start_pos + i..])
 }
 
 /// Performs a single deinflect operation, e.g.: 食べさせられたくなかった -> 食べさせられたくない
-pub fn deinflect_one_iteration(deinflected_word: &DeinflectedWord) -> Vec<DeinflectedWord> {
+pub fn deinflect_one_iteration<'a>(
+    rules: &'a Rules,
+    deinflected_word: &DeinflectedWord<'a>,
+) -> Vec<DeinflectedWord<'a>> {
     let mut results = Vec::new();
-    for suffix in capped_suffixes(deinflected_word.get_word()) {
-        if let Some(rules) = get_deinflection_rules(suffix) {
-            for rule in rules.iter() {
-                if let Some(deinflected) = rule.apply(deinflected_word, suffix.len()) {
-                    results.push(DeinflectedWord::new(deinflected, rule.rules_out));
-                }
+    for suffix in capped_suffixes(deinflected_word.get_word(), rules.max_suffix_length()) {
+        rules.for_each_rule(suffix, |rule| {
+            if let Some(deinflected) = rule.apply(deinflected_word, suffix.len()) {
+                // Nothing of the input is kept verbatim once the prefix is gone
+                let stem_len = if rule.kind.has_honorific_prefix() {
+                    0
+                } else {
+                    deinflected_word.get_word().len() - suffix.len()
+                };
+                results.push(DeinflectedWord {
+                    word: deinflected,
+                    types: rule.rules_out,
+                    rule: Some(rule),
+                    parent: None,
+                    stem_len: stem_len.min(deinflected_word.stem_len),
+                });
             }
-        }
+        });
     }
 
     results
 }
 
+/// A possible deinflection of a word together with the rules that lead to it
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Deinflection<'a> {
+    /// The deinflected (base) form
+    pub word: String,
+    /// The grammatical types the base form can have
+    pub types: &'a [RuleType],
+    /// The applied rules, ordered from the base form outwards, i.e. in the order in which the
+    /// inflections would have to be applied to `word` to get back the input
+    pub rules: Vec<DeinflectionRule<'a>>,
+    /// The byte span of the input that was consumed by the inflections, e.g. ッタ in 思ッタ.
+    /// Everything before it is the stem, which `word` shares with the input verbatim
+    /// (up to [`kata_to_hira`](crate::kata_to_hira) and, if the input was normalized,
+    /// [`normalize`](crate::normalize)).
+    /// The span always ends at the end of the input and is empty if no rules were applied.
+    pub inflection_span: Range<usize>,
+}
+
+impl Deinflection<'_> {
+    /// Returns the kinds of the applied inflections, ordered like [`Deinflection::rules`],
+    /// e.g. causative -> passive -> negative -> past for 食べさせられなかった -> 食べる
+    pub fn inflections(&self) -> impl Iterator<Item = InflectionKind> + '_ {
+        self.rules.iter().map(|rule| rule.kind)
+    }
+
+    /// Returns the deinflected word in the script of `input`, the word it was deinflected from:
+    /// the stem is taken from the input verbatim and the rest of the word is written in
+    /// katakana if the inflection was.
+    /// Returns `word` unchanged if it wasn't deinflected from `input`.
+    ///
+    /// # Examples
+    /// ```
+    /// use jp_deinflector::deinflect_detailed;
+    /// let input = "思ッタ";
+    /// let omou = deinflect_detailed(input).into_iter().find(|d| d.word == "思う").unwrap();
+    /// assert_eq!(&input[omou.inflection_span.clone()], "ッタ");
+    /// assert_eq!(omou.in_original_script(input), "思ウ");
+    /// ```
+    pub fn in_original_script(&self, input: &str) -> String {
+        let start = self.inflection_span.start;
+        let (Some(stem), Some(inflected)) =
+            (input.get(..start), input.get(self.inflection_span.clone()))
+        else {
+            return self.word.clone();
+        };
+        // The stem was normalized if the deinflector normalizes its input, and words that were
+        // taken as they are, like the ones of a scan, can still be in katakana
+        let word = kata_to_hira(&self.word);
+        let rest = [stem.to_string(), expand_iteration_marks(&fold_width(stem))]
+            .into_iter()
+            .chain(normalized_spellings(stem).0)
+            .find_map(|spelling| word.strip_prefix(kata_to_hira(&spelling).as_str()));
+        let Some(rest) = rest else {
+            return self.word.clone();
+        };
+
+        let inflected = fold_width(inflected);
+        let mut result = String::with_capacity(self.word.len());
+        result.push_str(stem);
+        if inflected.chars().any(is_katakana) && !inflected.chars().any(is_hiragana) {
+            result.push_str(&hira_to_kata(rest));
+        } else {
+            result.push_str(rest);
+        }
+        result
+    }
+}
+
+/// Lazily deinflects a word in breadth-first order.
+/// A deinflection is only deinflected further once all deinflections before it have been
+/// yielded, so stopping early avoids exploring deep chains.
+pub(crate) struct DeinflectionIter<'a> {
+    rules: &'a Rules,
+    deinflections: Vec<DeinflectedWord<'a>>,
+    seen_checker: SeenWordsTracker,
+    /// Index of the next deinflection to yield
+    next: usize,
+    /// Index of the next deinflection to deinflect further
+    next_to_expand: usize,
+    /// Length of the input, which is the end of all inflection spans
+    input_len: usize,
+}
+
+impl<'a> DeinflectionIter<'a> {
+    pub fn new(rules: &'a Rules, word: &str) -> Self {
+        let initial = DeinflectedWord::new(kata_to_hira(word), &[]);
+        let deinflections = deinflect_one_iteration(rules, &initial);
+
+        let mut seen_checker = SeenWordsTracker::new();
+        seen_checker.check_is_new(&initial);
+
+        Self {
+            rules,
+            deinflections,
+            seen_checker,
+            next: 0,
+            next_to_expand: 0,
+            input_len: initial.word.len(),
+        }
+    }
+
+    /// Deinflects the next deinflection further, unless its word has been seen before.
+    /// Returns false if there is nothing left to deinflect.
+    fn expand_next(&mut self) -> bool {
+        let i = self.next_to_expand;
+        let Some(current) = self.deinflections.get(i) else {
+            return false;
+        };
+        if self.seen_checker.check_is_new(current) {
+            let new_deinflections = deinflect_one_iteration(self.rules, current);
+            self.deinflections
+                .extend(new_deinflections.into_iter().map(|mut deinflection| {
+                    deinflection.parent = Some(i);
+                    deinflection
+                }));
+        }
+        self.next_to_expand += 1;
+        true
+    }
+}
+
+impl<'a> Iterator for DeinflectionIter<'a> {
+    type Item = Deinflection<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next >= self.deinflections.len() {
+            if !self.expand_next() {
+                return None;
+            }
+        }
+        let deinflection = &self.deinflections[self.next];
+        let item = Deinflection {
+            word: deinflection.word.clone(),
+            types: deinflection.types,
+            rules: collect_rules(&self.deinflections, self.next),
+            inflection_span: deinflection.stem_len..self.input_len,
+        };
+        self.next += 1;
+        Some(item)
+    }
+}
+
+/// Collects all deinflections reachable from the initial word in breadth-first order
+fn deinflect_all<'a>(rules: &'a Rules, word: &str) -> Vec<DeinflectedWord<'a>> {
+    let mut iter = DeinflectionIter::new(rules, word);
+    while iter.expand_next() {}
+    iter.deinflections
+}
+
+/// Follows the parent links of the deinflection at `index` back to the input.
+/// Since the rule of the deinflection itself was the last one to be undone, this yields the
+/// rules ordered from the base form outwards.
+fn collect_rules<'a>(
+    deinflections: &[DeinflectedWord<'a>],
+    index: usize,
+) -> Vec<DeinflectionRule<'a>> {
+    let mut rules = Vec::new();
+    let mut current = Some(index);
+    while let Some(i) = current {
+        let deinflection = &deinflections[i];
+        rules.extend(deinflection.rule);
+        current = deinflection.parent;
+    }
+    rules
+}
+
+/// Returns a list of possible deinflections for the given word, including the rules
+/// that were applied to obtain each of them.
+/// The results are in the same order as the ones of [`deinflect`].
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::{deinflect_detailed, RuleType};
+/// let deinflections = deinflect_detailed("食べさせられなかった");
+/// let taberu = deinflections.iter().find(|d| d.word == "食べる").unwrap();
+/// assert!(taberu.types.contains(&RuleType::V1));
+/// assert!(!taberu.rules.is_empty());
+/// ```
+pub fn deinflect_detailed(word: &str) -> Vec<Deinflection<'static>> {
+    deinflect_with_rules(&BUILTIN_RULES, word)
+}
+
+/// Deinflects `word` with the given rules, see [`deinflect_detailed`]
+pub(crate) fn deinflect_with_rules<'a>(rules: &'a Rules, word: &str) -> Vec<Deinflection<'a>> {
+    let deinflections = deinflect_all(rules, word);
+    // kata_to_hira keeps the length of the word, so the stem lengths are offsets into `word`
+    let input_len = word.len();
+    let rules: Vec<_> = (0..deinflections.len())
+        .map(|i| collect_rules(&deinflections, i))
+        .collect();
+
+    deinflections
+        .into_iter()
+        .zip(rules)
+        .map(|(deinflection, rules)| Deinflection {
+            word: deinflection.word,
+            types: deinflection.types,
+            rules,
+            inflection_span: deinflection.stem_len..input_len,
+        })
+        .collect()
+}
+
+/// Returns an iterator over the possible deinflections of the given word, which yields the
+/// same deinflections in the same order as [`deinflect_detailed`], but only explores as many
+/// chains of rules as are needed for the deinflections that are actually consumed.
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::deinflect_iter;
+/// // Stops as soon as 食べる has been found, without deinflecting any further
+/// let taberu = deinflect_iter("食べさせられなかった").find(|d| d.word == "食べる");
+/// assert_eq!(taberu.unwrap().rules.len(), 4);
+/// ```
+pub fn deinflect_iter(word: &str) -> impl Iterator<Item = Deinflection<'static>> {
+    DeinflectionIter::new(&BUILTIN_RULES, word)
+}
+
 /// Returns a list of possible deinflections for the given word.
 /// It doesn't guarantee that the returned deinflections are actual Japanese words, but if
 /// the words would exist, they would be deinflected correctly.
@@ -131,31 +578,32 @@ pub fn deinflect_one_iteration(deinflected_word: &DeinflectedWord) -> Vec<Deinfl
 /// assert!(deinflections.iter().any(|w| w == "待る"));
 /// ```
 pub fn deinflect(word: &str) -> Vec<String> {
-    let initial = DeinflectedWord::new(kata_to_hira(word), &[]);
-    let mut deinflections = deinflect_one_iteration(&initial);
-
-    let mut seen_checker = SeenWordsTracker::new();
-    seen_checker.check_is_new(&initial);
-
-    let mut i = 0;
-    while i < deinflections.len() {
-        let current = &deinflections[i];
-        if seen_checker.check_is_new(current) {
-            let new_deinflections = deinflect_one_iteration(current);
-            deinflections.extend(new_deinflections);
-        }
-        i += 1;
-    }
-
-    deinflections
+    deinflect_detailed(word)
         .into_iter()
         .map(|deinflection| deinflection.word)
         .collect()
 }
 
+/// Like [`deinflect`], but for words typed in romaji, which are converted with
+/// [`romaji_to_hira`](crate::romaji_to_hira) first. The returned words are written in hiragana.
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::deinflect_romaji;
+/// let deinflections = deinflect_romaji("ittenakatta");
+/// assert!(deinflections.iter().any(|w| w == "いく"));
+/// ```
+pub fn deinflect_romaji(word: &str) -> Vec<String> {
+    deinflect(&romaji_to_hira(word))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::deinflection_rules::MAX_SUFFIX_LENGTH;
+    use crate::deinflector::Deinflector;
+    use crate::inflect::inflect_with_max_depth;
+    use proptest::prelude::*;
 
     fn assert_deinflects_to(input: &str, expected: &str) {
         let result = deinflect(input);
@@ -380,6 +828,13 @@ mod tests {
         for case in cases {
             assert_deinflects_to(case, "誑かす");
         }
+
+        let deinflections = deinflect_detailed("話そう");
+        let hanasu = deinflections.iter().find(|d| d.word == "話す").unwrap();
+        assert_eq!(
+            hanasu.inflections().collect::<Vec<_>>(),
+            vec![InflectionKind::Volitional]
+        );
     }
 
     #[test]
@@ -594,6 +1049,77 @@ mod tests {
         assert_deinflects_to("早く", "早い");
     }
 
+    #[test]
+    fn test_deinflects_adjective_sa() {
+        assert_deinflects_to("高さ", "高い");
+        assert_deinflects_to("美しさ", "美しい");
+    }
+
+    #[test]
+    fn test_deinflects_adjective_mi() {
+        assert_deinflects_to("重み", "重い");
+        assert_deinflects_to("悲しみ", "悲しい");
+    }
+
+    #[test]
+    fn test_deinflects_adjective_ge() {
+        assert_deinflects_to("楽しげ", "楽しい");
+        assert_deinflects_to("危なげ", "危ない");
+    }
+
+    #[test]
+    fn test_deinflects_adjective_sugiru() {
+        assert_deinflects_to("高すぎる", "高い");
+        assert_deinflects_to("高すぎた", "高い");
+        assert_deinflects_to("良さすぎる", "良い");
+        assert_deinflects_to("よさすぎる", "いい");
+        assert_deinflects_to("無さすぎる", "無い");
+        assert_deinflects_to("少なすぎます", "少ない");
+    }
+
+    #[test]
+    fn test_deinflects_adjective_garu() {
+        assert_deinflects_to("寒がる", "寒い");
+        assert_deinflects_to("寒がっている", "寒い");
+        assert_deinflects_to("欲しがらない", "欲しい");
+    }
+
+    #[test]
+    fn test_deinflects_adjective_sou() {
+        assert_deinflects_to("高そう", "高い");
+        for (input, expected) in [
+            ("良さそう", "良い"),
+            ("好さそう", "好い"),
+            ("善さそう", "善い"),
+            ("よさそう", "よい"),
+            ("よさそう", "いい"),
+            ("無さそう", "無い"),
+            ("なさそう", "ない"),
+        ] {
+            assert_deinflects_to(input, expected);
+        }
+        assert_deinflects_to("食べなさそう", "食べる");
+    }
+
+    #[test]
+    fn test_deinflects_ii() {
+        for form in [
+            "よかった",
+            "よくない",
+            "よくなかった",
+            "よくて",
+            "よく",
+            "よければ",
+            "よさ",
+            "よさそう",
+            "よすぎる",
+        ] {
+            assert_deinflects_to(form, "いい");
+            assert_deinflects_to(form, "よい");
+        }
+        assert_deinflects_to("良かった", "良い");
+    }
+
     #[test]
     fn test_deinflects_compound_verbs() {
         let tobi_cases = [
@@ -688,7 +1214,7 @@ mod tests {
     #[test]
     fn test_get_suffixes() {
         let word = "走らされている";
-        let suffixes: Vec<&str> = capped_suffixes(word).collect();
+        let suffixes: Vec<&str> = capped_suffixes(word, MAX_SUFFIX_LENGTH).collect();
         assert_eq!(
             suffixes,
             vec![
@@ -703,7 +1229,7 @@ mod tests {
         );
 
         let word = "食べさせられなかった";
-        let suffixes: Vec<&str> = capped_suffixes(word).collect();
+        let suffixes: Vec<&str> = capped_suffixes(word, MAX_SUFFIX_LENGTH).collect();
         assert_eq!(
             suffixes,
             vec![
@@ -718,6 +1244,322 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get_suffixes_of_other_scripts() {
+        let suffixes: Vec<&str> = capped_suffixes("𠮟った", MAX_SUFFIX_LENGTH).collect();
+        assert_eq!(suffixes, vec!["𠮟った", "った", "た"]);
+
+        let suffixes: Vec<&str> = capped_suffixes("tabeた", 3).collect();
+        assert_eq!(suffixes, vec!["beた", "eた", "た"]);
+
+        let suffixes: Vec<&str> = capped_suffixes("ﾀﾍﾞﾀ", 2).collect();
+        assert_eq!(suffixes, vec!["ﾞﾀ", "ﾀ"]);
+
+        assert_eq!(capped_suffixes("", MAX_SUFFIX_LENGTH).count(), 0);
+        assert_eq!(capped_suffixes("食べた", 0).count(), 0);
+    }
+
+    #[test]
+    fn test_non_japanese_input() {
+        assert_deinflects_to("𠮟った", "𠮟る");
+        assert_deinflects_to("𩸽を食べた", "𩸽を食べる");
+        assert_deinflects_to("VRしている", "VRする");
+        assert!(deinflect("hello").is_empty());
+        assert!(deinflect("ﾀﾍﾞﾀ").is_empty());
+        assert!(deinflect("").is_empty());
+    }
+
+    #[test]
+    fn test_colloquial_contractions() {
+        let cases = [
+            ("食べてる", "食べる"),
+            ("見とく", "見る"),
+            ("書いとく", "書く"),
+            ("行かなきゃ", "行く"),
+            ("高けりゃ", "高い"),
+            ("食べなくちゃ", "食べる"),
+            ("知らねえ", "知る"),
+            ("わかんない", "わかる"),
+            ("わかんねえ", "わかる"),
+            ("行っちゃ", "行く"),
+            ("飲んじゃ", "飲む"),
+            ("来ちゃ", "来る"),
+        ];
+        for (input, expected) in cases {
+            assert_deinflects_to(input, expected);
+        }
+
+        let nakya = deinflect_detailed("行かなきゃ")
+            .into_iter()
+            .find(|d| d.word == "行く")
+            .unwrap();
+        assert_eq!(
+            nakya.inflections().collect::<Vec<_>>(),
+            vec![InflectionKind::Negative, InflectionKind::Kya]
+        );
+    }
+
+    #[test]
+    fn test_classical() {
+        let deinflector = Deinflector::new().with_classical(true);
+        let cases = [
+            (
+                "咲きたり",
+                "咲く",
+                RuleType::V5K,
+                InflectionKind::Perfective,
+            ),
+            ("咲けり", "咲く", RuleType::V5K, InflectionKind::Perfective),
+            ("行くべし", "行く", RuleType::V5KS, InflectionKind::Beshi),
+            ("静かなり", "静か", RuleType::AdjNa, InflectionKind::Nari),
+            (
+                "知らざる",
+                "知らざり",
+                RuleType::Vr,
+                InflectionKind::Attributive,
+            ),
+            ("知らざりき", "知る", RuleType::V5R, InflectionKind::Zaru),
+            ("ありけり", "あり", RuleType::Vr, InflectionKind::Keri),
+            ("行かむ", "行く", RuleType::V5K, InflectionKind::Mu),
+            ("死なむ", "死ぬ", RuleType::Vn, InflectionKind::Mu),
+            ("起くる", "起く", RuleType::V2K, InflectionKind::Attributive),
+            ("起きず", "起く", RuleType::V2K, InflectionKind::Zu),
+            ("受くれば", "受く", RuleType::V2S, InflectionKind::Ba),
+            ("受けけり", "受く", RuleType::V2S, InflectionKind::Keri),
+            ("高き", "高し", RuleType::AdjKu, InflectionKind::Attributive),
+            (
+                "美しき",
+                "美し",
+                RuleType::AdjShiku,
+                InflectionKind::Attributive,
+            ),
+            ("高けれ", "高し", RuleType::AdjKu, InflectionKind::Realis),
+        ];
+        for (input, expected, rule_type, kind) in cases {
+            let deinflections = deinflector.deinflect_detailed(input);
+            assert!(
+                deinflections.iter().any(|d| d.word == expected
+                    && d.types.contains(&rule_type)
+                    && d.rules[0].kind == kind),
+                "Input '{}' did not deinflect to '{}' ({:?}, {:?})",
+                input,
+                expected,
+                rule_type,
+                kind
+            );
+        }
+
+        // The classical rules are opt-in
+        assert_does_not_deinflect_to("起くる", "起く");
+        assert_does_not_deinflect_to("知らざりき", "知る");
+
+        assert_eq!(RuleType::from_pos_tag("v2k-s"), Some(RuleType::V2S));
+        assert_eq!(RuleType::from_pos_tag("v2y-k"), Some(RuleType::V2K));
+        assert_eq!(
+            RuleType::from_pos_tag("adj-shiku"),
+            Some(RuleType::AdjShiku)
+        );
+    }
+
+    #[test]
+    fn test_keigo() {
+        let cases = [
+            ("お読みになりました", "読む"),
+            ("ご覧になって", "ご覧になる"),
+            ("教えてくださった", "教える"),
+            ("召し上がっていらっしゃる", "召し上がる"),
+            ("使わせていただきます", "使う"),
+            ("読みなさる", "読む"),
+            ("勉強なさいました", "勉強する"),
+            ("ご説明になる", "説明する"),
+            ("お待ちしております", "待つ"),
+            ("ご案内いたします", "案内する"),
+            ("ください", "くださる"),
+            ("なさい", "なさる"),
+            ("いらっしゃい", "いらっしゃる"),
+            ("おっしゃい", "おっしゃる"),
+            ("くださいませ", "くださる"),
+        ];
+        for (input, expected) in cases {
+            assert_deinflects_to(input, expected);
+        }
+
+        let itadaku = deinflect_detailed("使わせていただきます")
+            .into_iter()
+            .find(|d| d.word == "使う")
+            .unwrap();
+        assert_eq!(
+            itadaku.inflections().collect::<Vec<_>>(),
+            vec![
+                InflectionKind::Causative,
+                InflectionKind::Te,
+                InflectionKind::Itadaku,
+                InflectionKind::Polite
+            ]
+        );
+
+        // The prefix is required and nothing of the input is kept verbatim without it
+        assert_does_not_deinflect_to("読みになる", "読む");
+        let yomu = deinflect_detailed("お読みになる")
+            .into_iter()
+            .find(|d| d.word == "読む")
+            .unwrap();
+        assert_eq!(yomu.inflection_span, 0.."お読みになる".len());
+    }
+
+    #[test]
+    fn test_honorific_prefix_needs_a_masu_stem() {
+        for (input, junk) in [
+            ("ご飯にする", ["飯ぬ", "飯にる", "飯にする"]),
+            ("お茶にする", ["茶ぬ", "茶にる", "茶にする"]),
+            ("ご飯にします", ["飯ぬ", "飯にる", "飯にする"]),
+        ] {
+            for word in junk {
+                assert_does_not_deinflect_to(input, word);
+            }
+        }
+        // The prefix is still stripped off masu stems and kango
+        assert_deinflects_to("お見せする", "見せる");
+        assert_deinflects_to("お知らせいたします", "知らせる");
+        assert_deinflects_to("お預かりする", "預かる");
+        assert_deinflects_to("ご連絡します", "連絡する");
+    }
+
+    #[test]
+    fn test_inflection_span() {
+        let input = "食べさせられなかった";
+        let deinflections = deinflect_detailed(input);
+        let taberu = deinflections.iter().find(|d| d.word == "食べる").unwrap();
+        assert_eq!(&input[taberu.inflection_span.clone()], "させられなかった");
+        // The stem of 食べさせる is longer
+        let tabesaseru = deinflections
+            .iter()
+            .find(|d| d.word == "食べさせる")
+            .unwrap();
+        assert_eq!(&input[tabesaseru.inflection_span.clone()], "られなかった");
+        // The stem ends where the ending of the last rule starts, e.g. いて -> く
+        let kaku = deinflect_detailed("書いて")
+            .into_iter()
+            .find(|d| d.word == "書く")
+            .unwrap();
+        assert_eq!(kaku.inflection_span, 3..9);
+    }
+
+    #[test]
+    fn test_in_original_script() {
+        let cases = [
+            ("思ッタ", "思う", "思ウ"),
+            ("思った", "思う", "思う"),
+            ("タベサセラレタ", "たべる", "タベル"),
+            // The stem stays as it is, even if it is mixed
+            ("たベた", "たべる", "たベる"),
+            ("食ベナイ", "食べる", "食ベル"),
+        ];
+        for (input, word, expected) in cases {
+            let deinflection = deinflect_detailed(input)
+                .into_iter()
+                .find(|d| d.word == word)
+                .unwrap();
+            assert_eq!(deinflection.in_original_script(input), expected);
+        }
+        // A different input doesn't fit the deinflection
+        let omou = deinflect_detailed("思ッタ")
+            .into_iter()
+            .find(|d| d.word == "思う")
+            .unwrap();
+        assert_eq!(omou.in_original_script("書いた"), "思う");
+        assert_eq!(omou.in_original_script(""), "思う");
+    }
+
+    #[test]
+    fn test_romaji_input() {
+        for (romaji, kana) in [
+            ("tabesaserareta", "たべさせられた"),
+            ("ittenakatta", "いってなかった"),
+            ("yomimasen", "よみません"),
+            ("shizukadeshita", "しずかでした"),
+            ("sinakereba", "しなければ"),
+            ("ikō", "いこう"),
+        ] {
+            assert_eq!(deinflect_romaji(romaji), deinflect(kana));
+        }
+        assert!(deinflect_romaji("ikō").iter().any(|w| w == "いく"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use crate::rule_set::OwnedDeinflectionRule;
+
+        let matsu = deinflect_detailed("待った")
+            .into_iter()
+            .find(|d| d.word == "待つ")
+            .unwrap();
+        let json = serde_json::to_value(&matsu).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "word": "待つ",
+                "types": ["V5T"],
+                "rules": [{
+                    "kana_out": "つ",
+                    "rules_in": [],
+                    "rules_out": ["V5T"],
+                    "kind": "Past",
+                }],
+                "inflection_span": { "start": 3, "end": 9 },
+            })
+        );
+
+        // Serialized rules can be read back as owned rules
+        let rule: OwnedDeinflectionRule = serde_json::from_value(json["rules"][0].clone()).unwrap();
+        assert_eq!(rule, OwnedDeinflectionRule::from(matsu.rules[0]));
+        let types: Vec<RuleType> = serde_json::from_value(json["types"].clone()).unwrap();
+        assert_eq!(types, matsu.types);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        use crate::deinflection_rules::all_rules;
+        use crate::rule_set::OwnedDeinflectionRule;
+
+        for rule in all_rules().flat_map(|(_, rules)| rules.iter()) {
+            let owned = OwnedDeinflectionRule::from(*rule);
+            let json = serde_json::to_string(&owned).unwrap();
+            let read: OwnedDeinflectionRule = serde_json::from_str(&json).unwrap();
+            assert_eq!(read, owned);
+            // The borrowed rule serializes the same way
+            assert_eq!(serde_json::to_string(rule).unwrap(), json);
+        }
+
+        // `Deinflection` borrows its types, so it is read back into owned data
+        #[derive(serde::Deserialize)]
+        struct OwnedDeinflection {
+            word: String,
+            types: Vec<RuleType>,
+            rules: Vec<OwnedDeinflectionRule>,
+            inflection_span: Range<usize>,
+        }
+
+        for word in [
+            "待った",
+            "食べさせられなかった",
+            "お読みになりました",
+            "寒くない",
+        ] {
+            for deinflection in deinflect_detailed(word) {
+                let json = serde_json::to_string(&deinflection).unwrap();
+                let read: OwnedDeinflection = serde_json::from_str(&json).unwrap();
+                assert_eq!(read.word, deinflection.word);
+                assert_eq!(read.types, deinflection.types);
+                let rules: Vec<_> = read.rules.iter().map(|rule| rule.as_rule()).collect();
+                assert_eq!(rules, deinflection.rules);
+                assert_eq!(read.inflection_span, deinflection.inflection_span);
+            }
+        }
+    }
+
     #[test]
     fn test_matsu() {
         let cases = [
@@ -807,81 +1649,328 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_deinflects_na_adjectives() {
+        let cases = [
+            "静かだ",
+            "静かだった",
+            "静かです",
+            "静かでした",
+            "静かじゃない",
+            "静かではない",
+            "静かじゃなかった",
+            "静かではなかった",
+            "静かじゃなくて",
+            "静かじゃありません",
+            "静かではありません",
+            "静かじゃありませんでした",
+            "静かではありませんでした",
+            "静かなら",
+            "静かで",
+            "静かな",
+            "静かに",
+            "静かだろう",
+            "静かでしょう",
+            "静かである",
+            "静かであれば",
+            "静かであった",
+            "静かそう",
+            "静かだったら",
+            "静かでしたら",
+            "静かだって",
+            "静かでない",
+            "静かでなければ",
+            "静かでございます",
+            "静かだったろう",
+        ];
+        for case in cases {
+            assert_deinflects_to(case, "静か");
+        }
+
+        assert_deinflects_to("綺麗じゃない", "綺麗");
+        assert_deinflects_to("有名でした", "有名");
+        assert_deinflects_to("元気なら", "元気");
+    }
+
+    #[test]
+    fn test_deinflects_copula() {
+        for case in [
+            "だった",
+            "です",
+            "でした",
+            "じゃない",
+            "ではなかった",
+            "なら",
+            "でしょう",
+        ] {
+            assert_deinflects_to(case, "だ");
+        }
+
+        let deinflections = deinflect_detailed("静かだった");
+        let shizuka = deinflections.iter().find(|d| d.word == "静か").unwrap();
+        assert_eq!(shizuka.types, &[RuleType::AdjNa]);
+        assert_eq!(
+            shizuka.inflections().collect::<Vec<_>>(),
+            vec![InflectionKind::Copula, InflectionKind::Past]
+        );
+    }
+
+    #[test]
+    fn test_deinflects_copula_forms() {
+        use InflectionKind::*;
+        let cases: [(&str, &[InflectionKind]); 7] = [
+            ("静かだったら", &[Copula, Tara]),
+            ("静かでしたら", &[Copula, Tara]),
+            ("静かだって", &[Copula, Te]),
+            ("静かでない", &[Copula, Negative]),
+            ("静かでなければ", &[Copula, Negative, Ba]),
+            ("静かでございます", &[Copula, Dearu, Polite]),
+            ("静かだったろう", &[Copula, Past, Volitional]),
+        ];
+        for (input, expected) in cases {
+            let deinflections = deinflect_detailed(input);
+            let shizuka = deinflections.iter().find(|d| d.word == "静か");
+            assert_eq!(
+                shizuka.map(|d| d.inflections().collect::<Vec<_>>()),
+                Some(expected.to_vec()),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_no_empty_deinflections() {
+        for word in ["だ", "だった", "な", "です"] {
+            assert!(!deinflect(word).iter().any(|w| w.is_empty()));
+        }
+    }
+
+    #[test]
+    fn test_godan_subclasses() {
+        let deinflections = deinflect_detailed("行った");
+        let iku = deinflections.iter().find(|d| d.word == "行く").unwrap();
+        assert_eq!(iku.types, &[RuleType::V5KS]);
+
+        let deinflections = deinflect_detailed("問うた");
+        let tou = deinflections.iter().find(|d| d.word == "問う").unwrap();
+        assert_eq!(tou.types, &[RuleType::V5US]);
+
+        // 書った is not a valid form of 書く
+        let deinflections = deinflect_detailed("書った");
+        assert!(!deinflections
+            .iter()
+            .any(|d| d.word == "書く" && d.types.contains(&RuleType::V5K)));
+
+        assert_deinflects_to("あった", "ある");
+        assert_deinflects_to("ありませんでした", "ある");
+    }
+
+    #[test]
+    fn test_deinflects_v5aru() {
+        let cases = [
+            (
+                "くださる",
+                vec![
+                    "ください",
+                    "くださいます",
+                    "くださいました",
+                    "くださった",
+                    "くださらない",
+                ],
+            ),
+            ("なさる", vec!["なさい", "なさいます", "なさいませんでした"]),
+            (
+                "いらっしゃる",
+                vec!["いらっしゃい", "いらっしゃいます", "いらっしゃって"],
+            ),
+            ("おっしゃる", vec!["おっしゃい", "おっしゃいました"]),
+            ("ござる", vec!["ございます", "ございません"]),
+        ];
+        for (expected, inputs) in cases {
+            for input in inputs {
+                assert_deinflects_to(input, expected);
+            }
+        }
+
+        let deinflections = deinflect_detailed("ください");
+        let kudasaru = deinflections.iter().find(|d| d.word == "くださる").unwrap();
+        assert_eq!(kudasaru.types, &[RuleType::V5Aru]);
+        // ください is both the imperative and the masu stem
+        assert!(deinflections
+            .iter()
+            .any(|d| d.word == "くださる" && d.inflections().eq([InflectionKind::MasuStem])));
+    }
+
+    #[test]
+    fn test_detailed_rule_chain() {
+        let deinflections = deinflect_detailed("待った");
+        let matsu = deinflections.iter().find(|d| d.word == "待つ").unwrap();
+        assert_eq!(matsu.types, &[RuleType::V5T]);
+        assert_eq!(matsu.rules.len(), 1);
+        assert_eq!(matsu.rules[0].kana_out, "つ");
+
+        // 食べる -(causative)-> 食べさせる -(passive)-> 食べさせられる -(negative)-> 食べさせられない
+        // -(past)-> 食べさせられなかった
+        let deinflections = deinflect_detailed("食べさせられなかった");
+        let taberu = deinflections.iter().find(|d| d.word == "食べる").unwrap();
+        let kana_outs: Vec<&str> = taberu.rules.iter().map(|r| r.kana_out).collect();
+        assert_eq!(kana_outs, vec!["る", "る", "る", "い"]);
+        assert_eq!(taberu.rules[0].rules_out, &[RuleType::V1]);
+        assert_eq!(taberu.rules[3].rules_out, &[RuleType::AdjI]);
+        assert_eq!(
+            taberu.inflections().collect::<Vec<_>>(),
+            vec![
+                InflectionKind::Causative,
+                InflectionKind::PotentialOrPassive,
+                InflectionKind::Negative,
+                InflectionKind::Past
+            ]
+        );
+    }
+
+    #[test]
+    fn test_iter_matches_detailed() {
+        for word in [
+            "食べさせられなかった",
+            "静かではありませんでした",
+            "待った",
+            "た",
+        ] {
+            let lazy: Vec<_> = deinflect_iter(word).collect();
+            let eager = deinflect_detailed(word);
+            assert_eq!(lazy.len(), eager.len());
+            for (a, b) in lazy.iter().zip(&eager) {
+                assert_eq!(a.word, b.word);
+                assert_eq!(a.types, b.types);
+                assert_eq!(a.rules, b.rules);
+            }
+        }
+    }
+
+    #[test]
+    fn test_iter_is_lazy() {
+        let word = "食べさせられなかった";
+        let mut iter = DeinflectionIter::new(&BUILTIN_RULES, word);
+        assert_eq!(iter.next().unwrap().word, "食べさせられない");
+        assert_eq!(iter.next_to_expand, 0);
+
+        assert!(iter.any(|d| d.word == "食べさせられる"));
+        assert!(iter.deinflections.len() < deinflect_all(&BUILTIN_RULES, word).len());
+    }
+
+    #[test]
+    fn test_detailed_matches_plain() {
+        let cases: [(&str, &[&str]); 4] = [
+            ("待った", &["待う", "待つ", "待る", "待っる"]),
+            ("思ッタ", &["思う", "思つ", "思る", "思っる"]),
+            ("食べた", &["食べる", "食ぶ"]),
+            ("", &[]),
+        ];
+        for (word, expected) in cases {
+            let detailed: Vec<String> = deinflect_detailed(word)
+                .into_iter()
+                .map(|d| d.word)
+                .collect();
+            assert_eq!(detailed, expected, "{word}");
+            assert_eq!(deinflect(word), expected, "{word}");
+        }
+    }
+
     #[test]
     fn test_problem_words() {
         assert_deinflects_to("損なわれ", "損なう");
         assert_deinflects_to("阻まれ", "阻む");
     }
 
-    // jp-inflctions unfortunately produces wrong inflections, which is why this is unusable
-    // Generate random "verbs" inflect them using 'jp-inflections', and test that they are deinflected correctly
-    // proptest! {
-    //     #[test]
-    //     fn test_godan_inflection_deinflection_roundtrip(base_verb in "(([あ-ん]){1,5}(う|く|ぐ|す|つ|ぬ|ぶ|む|る))"
-    //         .prop_filter("not_aru", |v| v != "ある")) {
-    //         // Create a base verb
-    //         let word = jp_inflections::Word::new(&base_verb, None);
-    //         if !word.is_verb() { return Ok(()); }
-    //
-    //         let verb = match word.into_verb(jp_inflections::VerbType::Godan) {
-    //             Ok(v) => v,
-    //             Err(_) => return Ok(()),
-    //         };
-    //
-    //         // Generate various inflections
-    //         let inflected_forms = vec![
-    //         ("dictionary long", verb.dictionary(jp_inflections::WordForm::Long).ok()),
-    //
-    //         // Negative forms
-    //         ("negative short", verb.negative(jp_inflections::WordForm::Short).ok()),
-    //         ("negative long", verb.negative(jp_inflections::WordForm::Long).ok()),
-    //         ("negative past short", verb.negative_past(jp_inflections::WordForm::Short).ok()),
-    //         ("negative past long", verb.negative_past(jp_inflections::WordForm::Long).ok()),
-    //
-    //         // Te forms
-    //         ("te form", verb.te_form().ok()),
-    //         ("negative te", verb.negative_te_form().ok()),
-    //
-    //         // Past forms
-    //         ("past short", verb.past(jp_inflections::WordForm::Short).ok()),
-    //         ("past long", verb.past(jp_inflections::WordForm::Long).ok()),
-    //
-    //         // Potential forms
-    //         ("potential short", verb.potential(jp_inflections::WordForm::Short).ok()),
-    //         ("potential long", verb.potential(jp_inflections::WordForm::Long).ok()),
-    //         ("negative potential short", verb.negative_potential(jp_inflections::WordForm::Short).ok()),
-    //         ("negative potential long", verb.negative_potential(jp_inflections::WordForm::Long).ok()),
-    //
-    //         // Passive forms
-    //         ("passive", verb.passive().ok()),
-    //         ("negative passive", verb.negative_passive().ok()),
-    //
-    //         // Causative forms
-    //         ("causative", verb.causative().ok()),
-    //         ("negative causative", verb.negative_causative().ok()),
-    //
-    //         // Causative-Passive forms
-    //         // ("causative passive", verb.causative_passive().ok()),
-    //         // ("negative causative passive", verb.negative_causative_passive().ok()),
-    //
-    //         // Imperative forms
-    //         ("imperative", verb.imperative().ok()),
-    //         ("negative imperative", verb.imperative_negative().ok()),
-    //     ];
-    //
-    //     for (inflection_type, maybe_inflected) in inflected_forms {
-    //         if let Some(inflected) = maybe_inflected {
-    //             let deinflections = deinflect(&inflected.kana);
-    //             prop_assert!(
-    //                 deinflections.iter().map(|d| d.get_word()).any(|w| w == &base_verb),
-    //                 "Failed to deinflect '{}' ({}) back to '{}'.\nAll deinflections: {:?}",
-    //                 inflected.kana,
-    //                 inflection_type,
-    //                 base_verb,
-    //                 deinflections
-    //             );
-    //         }
-    //     }
-    //     }
-    // }
+    #[test]
+    fn test_godan_roundtrip_regressions() {
+        // Found by test_godan_inflection_deinflection_roundtrip
+        for inflection in inflect_with_max_depth("一く", RuleType::V5K, 2) {
+            assert_deinflects_to(&inflection.word, "一く");
+        }
+    }
+
+    // Inflect random "verbs" and test that they are deinflected back correctly
+    proptest! {
+        #[test]
+        fn test_arbitrary_input_does_not_panic(s in any::<String>()) {
+            let deinflections = deinflect_detailed(&s);
+            for deinflection in &deinflections {
+                prop_assert!(!deinflection.word.is_empty());
+                prop_assert!(!deinflection.rules.is_empty());
+            }
+
+            let mut deinflector = Deinflector::new();
+            let mut out = Vec::new();
+            deinflector.deinflect_into(&s, &mut out);
+            prop_assert_eq!(out, deinflect(&s));
+        }
+
+        #[test]
+        fn test_stem_is_kept_verbatim(s in "[一-龠ぁ-ゖァ-ヶ]{0,8}") {
+            let hira = kata_to_hira(&s);
+            for deinflection in deinflect_detailed(&s) {
+                let span = deinflection.inflection_span.clone();
+                prop_assert_eq!(span.end, s.len());
+                prop_assert!(span.start < span.end);
+                prop_assert_eq!(&deinflection.word[..span.start], &hira[..span.start]);
+                prop_assert_eq!(kata_to_hira(&deinflection.in_original_script(&s)), deinflection.word);
+            }
+        }
+
+        #[test]
+        fn test_suffixes_of_arbitrary_input(s in any::<String>(), max_suffix_length in 0..10usize) {
+            let suffixes: Vec<&str> = capped_suffixes(&s, max_suffix_length).collect();
+            prop_assert_eq!(suffixes.len(), s.chars().count().min(max_suffix_length));
+            for (i, suffix) in suffixes.iter().enumerate() {
+                prop_assert!(s.ends_with(suffix));
+                prop_assert_eq!(suffix.chars().count(), suffixes.len() - i);
+            }
+        }
+
+        #[test]
+        fn test_godan_inflection_deinflection_roundtrip(
+            base_verb in "[一-龠]{1,2}(う|く|ぐ|す|つ|ぬ|ぶ|む|る)"
+        ) {
+            let rule_type = match base_verb.chars().last().unwrap() {
+                'う' => RuleType::V5U,
+                'く' => RuleType::V5K,
+                'ぐ' => RuleType::V5G,
+                'す' => RuleType::V5S,
+                'つ' => RuleType::V5T,
+                'ぬ' => RuleType::V5N,
+                'ぶ' => RuleType::V5B,
+                'む' => RuleType::V5M,
+                _ => RuleType::V5R,
+            };
+            for inflection in inflect_with_max_depth(&base_verb, rule_type, 2) {
+                let deinflections = deinflect(&inflection.word);
+                prop_assert!(
+                    deinflections.contains(&base_verb),
+                    "Failed to deinflect '{}' ({:?}) back to '{}'.\nAll deinflections: {:?}",
+                    inflection.word,
+                    inflection.inflections().collect::<Vec<_>>(),
+                    base_verb,
+                    deinflections
+                );
+            }
+        }
+
+        #[test]
+        fn test_ichidan_inflection_deinflection_roundtrip(
+            base_verb in "[一-龠]{1,2}(え|け|せ|て|ね|べ|め|れ|い|き|じ|ち|み|り)る"
+        ) {
+            for inflection in inflect_with_max_depth(&base_verb, RuleType::V1, 2) {
+                let deinflections = deinflect(&inflection.word);
+                prop_assert!(
+                    deinflections.contains(&base_verb),
+                    "Failed to deinflect '{}' ({:?}) back to '{}'.\nAll deinflections: {:?}",
+                    inflection.word,
+                    inflection.inflections().collect::<Vec<_>>(),
+                    base_verb,
+                    deinflections
+                );
+            }
+        }
+    }
 }
@@ -1,3822 +1,9819 @@
 use crate::deinflect::{DeinflectionRule, RuleType};
+use crate::inflection_kind::InflectionKind;
 
 // This is maximum number of suffix lengths that we check against this list
 pub const MAX_SUFFIX_LENGTH: usize = 7;
 
-pub fn get_deinflection_rules(suffix: &str) -> Option<&'static [DeinflectionRule]> {
-    hashify::tiny_map! {
-        suffix.as_bytes(),
-        "ければ" => &[
-            DeinflectionRule {
-                kana_out: "い",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-        "えば" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "けば" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "げば" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "せば" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "てば" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ねば" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "べば" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "めば" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "れば" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1, RuleType::V5, RuleType::Vk, RuleType::Vs, RuleType::Vz],
-            },
-        ],
-        "ちゃう" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "いじゃう" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "いちゃう" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "しちゃう" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "っちゃう" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "んじゃう" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じちゃう" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "為ちゃう" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "きちゃう" => &[
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "来ちゃう" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來ちゃう" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "ちまう" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "いじまう" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "いちまう" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "しちまう" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "っちまう" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "んじまう" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じちまう" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "為ちまう" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "きちまう" => &[
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "来ちまう" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來ちまう" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "てしまう" => &[
-            DeinflectionRule {
-                kana_out: "て",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Iru],
-            },
-        ],
-        "でしまう" => &[
-            DeinflectionRule {
-                kana_out: "で",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Iru],
-            },
-        ],
-        "なさい" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "いなさい" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "きなさい" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "ぎなさい" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "しなさい" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "ちなさい" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "になさい" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "びなさい" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "みなさい" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "りなさい" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じなさい" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "為なさい" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "来なさい" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來なさい" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "そう" => &[
-            DeinflectionRule {
-                kana_out: "い",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "いそう" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "きそう" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "ぎそう" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "しそう" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "ちそう" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "にそう" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "びそう" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "みそう" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "りそう" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じそう" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "為そう" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "来そう" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來そう" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "すぎる" => &[
-            DeinflectionRule {
-                kana_out: "い",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::AdjI],
-            },
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "いすぎる" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "きすぎる" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "ぎすぎる" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "しすぎる" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "ちすぎる" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "にすぎる" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
+/// Generates a function `$get`, a perfect hash map from suffixes to their rules,
+/// and a static `$all`, which lists the same entries to allow iterating over them
+macro_rules! deinflection_rules {
+    ($get:ident, $all:ident; $($suffix:literal => $rules:expr),* $(,)?) => {
+        pub fn $get(suffix: &str) -> Option<&'static [DeinflectionRule<'static>]> {
+            hashify::tiny_map! {
+                suffix.as_bytes(),
+                $($suffix => $rules,)*
+            }
+        }
+
+        /// All entries of the rule table as (suffix, rules) pairs
+        pub static $all: &[(&str, &[DeinflectionRule<'static>])] = &[$(($suffix, $rules),)*];
+    };
+}
+
+/// All entries of the tables that are enabled by default, the colloquial ones last
+pub fn all_rules(
+) -> impl Iterator<Item = &'static (&'static str, &'static [DeinflectionRule<'static>])> {
+    DEINFLECTION_RULES.iter().chain(COLLOQUIAL_RULES)
+}
+
+deinflection_rules! {
+    get_deinflection_rules, DEINFLECTION_RULES;
+    "ければ" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "えば" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "けば" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "げば" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "せば" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "てば" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "ねば" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "べば" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "めば" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "れば" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1, RuleType::V5R, RuleType::V5RI, RuleType::V5Aru, RuleType::Vk, RuleType::Vs, RuleType::Vz],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "てしまう" => &[
+        DeinflectionRule {
+            kana_out: "て",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Shimau,
+        },
+    ],
+    "でしまう" => &[
+        DeinflectionRule {
+            kana_out: "で",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Shimau,
+        },
+    ],
+    "なさい" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Nasai,
+        },
+    ],
+    "いなさい" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Nasai,
+        },
+    ],
+    "きなさい" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Nasai,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Nasai,
+        },
+    ],
+    "ぎなさい" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Nasai,
+        },
+    ],
+    "しなさい" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Nasai,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Nasai,
+        },
+    ],
+    "ちなさい" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Nasai,
+        },
+    ],
+    "になさい" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Nasai,
+        },
+    ],
+    "びなさい" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Nasai,
+        },
+    ],
+    "みなさい" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Nasai,
+        },
+    ],
+    "りなさい" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::Nasai,
+        },
+    ],
+    "じなさい" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Nasai,
+        },
+    ],
+    "為なさい" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Nasai,
+        },
+    ],
+    "来なさい" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Nasai,
+        },
+    ],
+    "來なさい" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Nasai,
+        },
+    ],
+    "そう" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Sou,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Sou,
+        },
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Volitional,
+        },
+        DeinflectionRule {
+            kana_out: "",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjNa],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "いそう" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "きそう" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Sou,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "ぎそう" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "しそう" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Sou,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "ちそう" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "にそう" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "びそう" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "みそう" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "りそう" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "じそう" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "為そう" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "来そう" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "來そう" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "すぎる" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Sugiru,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Sugiru,
+        },
+    ],
+    "いすぎる" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Sugiru,
+        },
+    ],
+    "きすぎる" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Sugiru,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Sugiru,
+        },
+    ],
+    "ぎすぎる" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Sugiru,
+        },
+    ],
+    "しすぎる" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Sugiru,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Sugiru,
+        },
+    ],
+    "ちすぎる" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Sugiru,
+        },
+    ],
+    "にすぎる" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Sugiru,
+        },
+    ],
     "びすぎる" => &[
-    DeinflectionRule {
-    kana_out: "ぶ",
-    rules_in: &[RuleType::V1],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Sugiru,
+        },
     ],
     "みすぎる" => &[
-    DeinflectionRule {
-    kana_out: "む",
-    rules_in: &[RuleType::V1],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Sugiru,
+        },
     ],
     "りすぎる" => &[
-    DeinflectionRule {
-    kana_out: "る",
-    rules_in: &[RuleType::V1],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::Sugiru,
+        },
     ],
     "じすぎる" => &[
-    DeinflectionRule {
-    kana_out: "ずる",
-    rules_in: &[RuleType::V1],
-    rules_out: &[RuleType::Vz],
-    },
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Sugiru,
+        },
     ],
     "為すぎる" => &[
-    DeinflectionRule {
-    kana_out: "為る",
-    rules_in: &[RuleType::V1],
-    rules_out: &[RuleType::Vs],
-    },
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Sugiru,
+        },
     ],
     "来すぎる" => &[
-    DeinflectionRule {
-    kana_out: "来る",
-    rules_in: &[RuleType::V1],
-    rules_out: &[RuleType::Vk],
-    },
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Sugiru,
+        },
     ],
     "來すぎる" => &[
-    DeinflectionRule {
-    kana_out: "來る",
-    rules_in: &[RuleType::V1],
-    rules_out: &[RuleType::Vk],
-    },
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Sugiru,
+        },
     ],
     "たい" => &[
-    DeinflectionRule {
-    kana_out: "る",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::V1],
-    },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Tai,
+        },
     ],
     "いたい" => &[
-    DeinflectionRule {
-    kana_out: "う",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Tai,
+        },
     ],
     "きたい" => &[
-    DeinflectionRule {
-    kana_out: "く",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::V5],
-    },
-            DeinflectionRule {
-    kana_out: "くる",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::Vk],
-    },
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Tai,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Tai,
+        },
     ],
     "ぎたい" => &[
-    DeinflectionRule {
-    kana_out: "ぐ",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Tai,
+        },
     ],
     "したい" => &[
-    DeinflectionRule {
-    kana_out: "す",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::V5],
-    },
-            DeinflectionRule {
-    kana_out: "する",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::Vs],
-    },
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Tai,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Tai,
+        },
     ],
     "ちたい" => &[
-    DeinflectionRule {
-    kana_out: "つ",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Tai,
+        },
     ],
     "にたい" => &[
-    DeinflectionRule {
-    kana_out: "ぬ",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Tai,
+        },
     ],
     "びたい" => &[
-    DeinflectionRule {
-    kana_out: "ぶ",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Tai,
+        },
     ],
     "みたい" => &[
-    DeinflectionRule {
-    kana_out: "む",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Tai,
+        },
     ],
     "りたい" => &[
-    DeinflectionRule {
-    kana_out: "る",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::Tai,
+        },
     ],
     "じたい" => &[
-    DeinflectionRule {
-    kana_out: "ずる",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::Vz],
-    },
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Tai,
+        },
     ],
     "為たい" => &[
-    DeinflectionRule {
-    kana_out: "為る",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::Vs],
-    },
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Tai,
+        },
     ],
     "来たい" => &[
-    DeinflectionRule {
-    kana_out: "来る",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::Vk],
-    },
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Tai,
+        },
     ],
     "來たい" => &[
-    DeinflectionRule {
-    kana_out: "來る",
-    rules_in: &[RuleType::AdjI],
-    rules_out: &[RuleType::Vk],
-    },
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Tai,
+        },
     ],
     "かったら" => &[
-    DeinflectionRule {
-    kana_out: "い",
-    rules_in: &[],
-    rules_out: &[RuleType::AdjI],
-    },
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Tara,
+        },
     ],
     "たら" => &[
-    DeinflectionRule {
-    kana_out: "る",
-    rules_in: &[],
-    rules_out: &[RuleType::V1],
-    },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Tara,
+        },
     ],
     "いたら" => &[
-    DeinflectionRule {
-    kana_out: "く",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K],
+            kind: InflectionKind::Tara,
+        },
     ],
     "いだら" => &[
-    DeinflectionRule {
-    kana_out: "ぐ",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Tara,
+        },
     ],
     "したら" => &[
-    DeinflectionRule {
-    kana_out: "す",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
-            DeinflectionRule {
-    kana_out: "する",
-    rules_in: &[],
-    rules_out: &[RuleType::Vs],
-    },
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Tara,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Tara,
+        },
     ],
     "ったら" => &[
-    DeinflectionRule {
-    kana_out: "う",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
-    DeinflectionRule {
-    kana_out: "つ",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
-    DeinflectionRule {
-    kana_out: "る",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U],
+            kind: InflectionKind::Tara,
+        },
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Tara,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI, RuleType::V5Aru],
+            kind: InflectionKind::Tara,
+        },
     ],
     "んだら" => &[
-    DeinflectionRule {
-    kana_out: "ぬ",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
-    DeinflectionRule {
-    kana_out: "ぶ",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
-    DeinflectionRule {
-    kana_out: "む",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Tara,
+        },
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Tara,
+        },
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Tara,
+        },
     ],
     "じたら" => &[
-    DeinflectionRule {
-    kana_out: "ずる",
-    rules_in: &[],
-    rules_out: &[RuleType::Vz],
-    },
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Tara,
+        },
     ],
     "為たら" => &[
-    DeinflectionRule {
-    kana_out: "為る",
-    rules_in: &[],
-    rules_out: &[RuleType::Vs],
-    },
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Tara,
+        },
     ],
     "きたら" => &[
-    DeinflectionRule {
-    kana_out: "くる",
-    rules_in: &[],
-    rules_out: &[RuleType::Vk],
-    },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Tara,
+        },
     ],
     "来たら" => &[
-    DeinflectionRule {
-    kana_out: "来る",
-    rules_in: &[],
-    rules_out: &[RuleType::Vk],
-    },
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Tara,
+        },
     ],
     "來たら" => &[
-    DeinflectionRule {
-    kana_out: "來る",
-    rules_in: &[],
-    rules_out: &[RuleType::Vk],
-    },
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Tara,
+        },
     ],
     "いったら" => &[
-    DeinflectionRule {
-    kana_out: "いく",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "いく",
+            rules_in: &[],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Tara,
+        },
     ],
     "おうたら" => &[
-    DeinflectionRule {
-    kana_out: "おう",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "おう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tara,
+        },
     ],
     "こうたら" => &[
-    DeinflectionRule {
-    kana_out: "こう",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "こう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tara,
+        },
     ],
     "そうたら" => &[
-    DeinflectionRule {
-    kana_out: "そう",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "そう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tara,
+        },
     ],
     "とうたら" => &[
-    DeinflectionRule {
-    kana_out: "とう",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "とう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tara,
+        },
     ],
     "行ったら" => &[
-    DeinflectionRule {
-    kana_out: "行く",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "行く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Tara,
+        },
     ],
     "逝ったら" => &[
-    DeinflectionRule {
-    kana_out: "逝く",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "逝く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Tara,
+        },
     ],
     "往ったら" => &[
-    DeinflectionRule {
-    kana_out: "往く",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "往く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Tara,
+        },
     ],
     "請うたら" => &[
-    DeinflectionRule {
-    kana_out: "請う",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "請う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tara,
+        },
     ],
     "乞うたら" => &[
-    DeinflectionRule {
-    kana_out: "乞う",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "乞う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tara,
+        },
     ],
     "恋うたら" => &[
-    DeinflectionRule {
-    kana_out: "恋う",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "恋う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tara,
+        },
     ],
     "問うたら" => &[
-    DeinflectionRule {
-    kana_out: "問う",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "問う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tara,
+        },
     ],
     "負うたら" => &[
-    DeinflectionRule {
-    kana_out: "負う",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "負う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tara,
+        },
     ],
     "沿うたら" => &[
-    DeinflectionRule {
-    kana_out: "沿う",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "沿う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tara,
+        },
     ],
     "添うたら" => &[
-    DeinflectionRule {
-    kana_out: "添う",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "添う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tara,
+        },
     ],
     "副うたら" => &[
-    DeinflectionRule {
-    kana_out: "副う",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "副う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tara,
+        },
     ],
     "厭うたら" => &[
-    DeinflectionRule {
-    kana_out: "厭う",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "厭う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tara,
+        },
     ],
     "のたもうたら" => &[
-    DeinflectionRule {
-    kana_out: "のたまう",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "のたまう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tara,
+        },
     ],
     "かったり" => &[
-    DeinflectionRule {
-    kana_out: "い",
-    rules_in: &[],
-    rules_out: &[RuleType::AdjI],
-    },
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Tari,
+        },
     ],
     "たり" => &[
-    DeinflectionRule {
-    kana_out: "る",
-    rules_in: &[],
-    rules_out: &[RuleType::V1],
-    },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Tari,
+        },
     ],
     "いたり" => &[
-    DeinflectionRule {
-    kana_out: "く",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K],
+            kind: InflectionKind::Tari,
+        },
     ],
     "いだり" => &[
-    DeinflectionRule {
-    kana_out: "ぐ",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Tari,
+        },
     ],
     "したり" => &[
-    DeinflectionRule {
-    kana_out: "す",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
-            DeinflectionRule {
-    kana_out: "する",
-    rules_in: &[],
-    rules_out: &[RuleType::Vs],
-    },
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Tari,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Tari,
+        },
     ],
     "ったり" => &[
-    DeinflectionRule {
-    kana_out: "う",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
-    DeinflectionRule {
-    kana_out: "つ",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
-    DeinflectionRule {
-    kana_out: "る",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U],
+            kind: InflectionKind::Tari,
+        },
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Tari,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI, RuleType::V5Aru],
+            kind: InflectionKind::Tari,
+        },
     ],
     "んだり" => &[
-    DeinflectionRule {
-    kana_out: "ぬ",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
-    DeinflectionRule {
-    kana_out: "ぶ",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
-    DeinflectionRule {
-    kana_out: "む",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Tari,
+        },
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Tari,
+        },
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Tari,
+        },
     ],
     "じたり" => &[
-    DeinflectionRule {
-    kana_out: "ずる",
-    rules_in: &[],
-    rules_out: &[RuleType::Vz],
-    },
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Tari,
+        },
     ],
     "為たり" => &[
-    DeinflectionRule {
-    kana_out: "為る",
-    rules_in: &[],
-    rules_out: &[RuleType::Vs],
-    },
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Tari,
+        },
     ],
     "きたり" => &[
-    DeinflectionRule {
-    kana_out: "くる",
-    rules_in: &[],
-    rules_out: &[RuleType::Vk],
-    },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Tari,
+        },
     ],
     "来たり" => &[
-    DeinflectionRule {
-    kana_out: "来る",
-    rules_in: &[],
-    rules_out: &[RuleType::Vk],
-    },
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Tari,
+        },
     ],
     "來たり" => &[
-    DeinflectionRule {
-    kana_out: "來る",
-    rules_in: &[],
-    rules_out: &[RuleType::Vk],
-    },
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Tari,
+        },
     ],
     "いったり" => &[
-    DeinflectionRule {
-    kana_out: "いく",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "いく",
+            rules_in: &[],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Tari,
+        },
     ],
     "おうたり" => &[
-    DeinflectionRule {
-    kana_out: "おう",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "おう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tari,
+        },
     ],
     "こうたり" => &[
-    DeinflectionRule {
-    kana_out: "こう",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "こう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tari,
+        },
     ],
     "そうたり" => &[
-    DeinflectionRule {
-    kana_out: "そう",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "そう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tari,
+        },
     ],
     "とうたり" => &[
-    DeinflectionRule {
-    kana_out: "とう",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "とう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tari,
+        },
     ],
     "行ったり" => &[
-    DeinflectionRule {
-    kana_out: "行く",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "行く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Tari,
+        },
     ],
     "逝ったり" => &[
-    DeinflectionRule {
-    kana_out: "逝く",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "逝く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Tari,
+        },
     ],
     "往ったり" => &[
-    DeinflectionRule {
-    kana_out: "往く",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "往く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Tari,
+        },
     ],
     "請うたり" => &[
-    DeinflectionRule {
-    kana_out: "請う",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
+        DeinflectionRule {
+            kana_out: "請う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tari,
+        },
     ],
     "乞うたり" => &[
-    DeinflectionRule {
-    kana_out: "乞う",
-    rules_in: &[],
-    rules_out: &[RuleType::V5],
-    },
-    ],
-        "恋うたり" => &[
-            DeinflectionRule {
-                kana_out: "恋う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "問うたり" => &[
-            DeinflectionRule {
-                kana_out: "問う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "負うたり" => &[
-            DeinflectionRule {
-                kana_out: "負う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "沿うたり" => &[
-            DeinflectionRule {
-                kana_out: "沿う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "添うたり" => &[
-            DeinflectionRule {
-                kana_out: "添う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "副うたり" => &[
-            DeinflectionRule {
-                kana_out: "副う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "厭うたり" => &[
-            DeinflectionRule {
-                kana_out: "厭う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "のたもうたり" => &[
-            DeinflectionRule {
-                kana_out: "のたまう",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "くて" => &[
-            DeinflectionRule {
-                kana_out: "い",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-        "て" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V1],
-            },
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "てる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "いて" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "いで" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "して" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "って" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "んで" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じて" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "為て" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "きて" => &[
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "来て" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來て" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "いって" => &[
-            DeinflectionRule {
-                kana_out: "いく",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "おうて" => &[
-            DeinflectionRule {
-                kana_out: "おう",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "こうて" => &[
-            DeinflectionRule {
-                kana_out: "こう",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "そうて" => &[
-            DeinflectionRule {
-                kana_out: "そう",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "とうて" => &[
-            DeinflectionRule {
-                kana_out: "とう",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "行って" => &[
-            DeinflectionRule {
-                kana_out: "行く",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "逝って" => &[
-            DeinflectionRule {
-                kana_out: "逝く",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "往って" => &[
-            DeinflectionRule {
-                kana_out: "往く",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "請うて" => &[
-            DeinflectionRule {
-                kana_out: "請う",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "乞うて" => &[
-            DeinflectionRule {
-                kana_out: "乞う",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "恋うて" => &[
-            DeinflectionRule {
-                kana_out: "恋う",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "問うて" => &[
-            DeinflectionRule {
-                kana_out: "問う",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "負うて" => &[
-            DeinflectionRule {
-                kana_out: "負う",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "沿うて" => &[
-            DeinflectionRule {
-                kana_out: "沿う",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "添うて" => &[
-            DeinflectionRule {
-                kana_out: "添う",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "副うて" => &[
-            DeinflectionRule {
-                kana_out: "副う",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "厭うて" => &[
-            DeinflectionRule {
-                kana_out: "厭う",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "のたもうて" => &[
-            DeinflectionRule {
-                kana_out: "のたまう",
-                rules_in: &[RuleType::Iru],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ず" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "かず" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "がず" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "さず" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "たず" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "なず" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ばず" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "まず" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "らず" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "わず" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ぜず" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "せず" => &[
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "為ず" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "こず" => &[
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "来ず" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來ず" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "ぬ" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "かぬ" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "がぬ" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "さぬ" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "たぬ" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "なぬ" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ばぬ" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "まぬ" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "らぬ" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "わぬ" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ぜぬ" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "せぬ" => &[
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "為ぬ" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "こぬ" => &[
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "来ぬ" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來ぬ" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "く" => &[
-            DeinflectionRule {
-                kana_out: "い",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-        "させる" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V1],
-            },
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "かせる" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "がせる" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "たせる" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "なせる" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ばせる" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ませる" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "らせる" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "わせる" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-                },
-        ],
-        "じさせる" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "ぜさせる" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "為せる" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "せさせる" => &[
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "為させる" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "こさせる" => &[
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "来させる" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來させる" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "ろ" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "よ" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "え" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "える",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "け" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "ける",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "げ" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "げる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "せ" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "せる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "ね" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "ねる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "べ" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "べる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "め" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "める",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "れ" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "れる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "じろ" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "ぜよ" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "しろ" => &[
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "せよ" => &[
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "為ろ" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "為よ" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "こい" => &[
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "来い" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來い" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "な" => &[
-            DeinflectionRule {
-                kana_out: "",
-                rules_in: &[],
-                rules_out: &[RuleType::V1, RuleType::V5, RuleType::Vk, RuleType::Vs, RuleType::Vz],
-            },
-        ],
-        "い" => &[
-            DeinflectionRule {
-                kana_out: "いる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "き" => &[
-            DeinflectionRule {
-                kana_out: "きる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-            DeinflectionRule {
-                kana_out: "い",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-        "ぎ" => &[
-            DeinflectionRule {
-                kana_out: "ぎる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じ" => &[
-            DeinflectionRule {
-                kana_out: "じる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "ぜ" => &[
-            DeinflectionRule {
-                kana_out: "ぜる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "ち" => &[
-            DeinflectionRule {
-                kana_out: "ちる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "で" => &[
-            DeinflectionRule {
-                kana_out: "でる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "に" => &[
-            DeinflectionRule {
-                kana_out: "にる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ひ" => &[
-            DeinflectionRule {
-                kana_out: "ひる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "び" => &[
-            DeinflectionRule {
-                kana_out: "びる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "へ" => &[
-            DeinflectionRule {
-                kana_out: "へる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "み" => &[
-            DeinflectionRule {
-                kana_out: "みる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "り" => &[
-            DeinflectionRule {
-                kana_out: "りる",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "し" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            }
-        ],
-        "来" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "くない" => &[
-            DeinflectionRule {
-                kana_out: "い",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-        "ない" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "かない" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "がない" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "さない" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "たない" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "なない" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ばない" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "まない" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "らない" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "わない" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じない" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "しない" => &[
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "為ない" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "こない" => &[
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "来ない" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來ない" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[RuleType::AdjI],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "さ" => &[
-            DeinflectionRule {
-                kana_out: "い",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-        "かれる" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "がれる" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "される" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "たれる" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "なれる" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ばれる" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "まれる" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "われる" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-                },
-        ],
-        "られる" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5, RuleType::V1],
-            }
-        ],
-        "じされる" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-            "ぜされる" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "為れる" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "こられる" => &[
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-         "来られる" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-         "來られる" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "かった" => &[
-            DeinflectionRule {
-                kana_out: "い",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-        "た" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "いた" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-         "いだ" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "した" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "った" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "んだ" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じた" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-          "為た" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-         "きた" => &[
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "来た" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來た" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "いった" => &[
-            DeinflectionRule {
-                kana_out: "いく",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "おうた" => &[
-            DeinflectionRule {
-                kana_out: "おう",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "こうた" => &[
-            DeinflectionRule {
-                kana_out: "こう",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "そうた" => &[
-            DeinflectionRule {
-                kana_out: "そう",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "とうた" => &[
-            DeinflectionRule {
-                kana_out: "とう",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "行った" => &[
-            DeinflectionRule {
-                kana_out: "行く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "逝った" => &[
-            DeinflectionRule {
-                kana_out: "逝く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "往った" => &[
-            DeinflectionRule {
-                kana_out: "往く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-          "請うた" => &[
-            DeinflectionRule {
-                kana_out: "請う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "乞うた" => &[
-            DeinflectionRule {
-                kana_out: "乞う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "恋うた" => &[
-            DeinflectionRule {
-                kana_out: "恋う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "問うた" => &[
-            DeinflectionRule {
-                kana_out: "問う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "負うた" => &[
-            DeinflectionRule {
-                kana_out: "負う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "沿うた" => &[
-            DeinflectionRule {
-                kana_out: "沿う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "添うた" => &[
-            DeinflectionRule {
-                kana_out: "添う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "副うた" => &[
-            DeinflectionRule {
-                kana_out: "副う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "厭うた" => &[
-            DeinflectionRule {
-                kana_out: "厭う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-            "のたもうた" => &[
-            DeinflectionRule {
-                kana_out: "のたまう",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-       "ます" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "います" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "きます" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-          "ぎます" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "します" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-             DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "ちます" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "にます" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "びます" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "みます" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ります" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じます" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "為ます" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "来ます" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來ます" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "くありません" => &[
-            DeinflectionRule {
-                kana_out: "い",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-        "ません" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                 rules_out: &[RuleType::V1],
-            },
-        ],
-        "いません" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "きません" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-         "ぎません" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "しません" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "する",
-                 rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "ちません" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "にません" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "びません" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "みません" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-         "りません" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じません" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-         "為ません" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-         "来ません" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                 rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來ません" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "ました" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-         "いました" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-          "きました" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "ぎました" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-         "しました" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-             DeinflectionRule {
-                kana_out: "する",
-                 rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "ちました" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "にました" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "びました" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "みました" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "りました" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じました" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                 rules_in: &[],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "為ました" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "来ました" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來ました" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-         "くありませんでした" => &[
-            DeinflectionRule {
-                kana_out: "い",
-                 rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-         "ませんでした" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                 rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-         "いませんでした" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "きませんでした" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-         "ぎませんでした" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-         "しませんでした" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-             DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "ちませんでした" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-         "にませんでした" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "びませんでした" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-         "みませんでした" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "りませんでした" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じませんでした" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                 rules_in: &[],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "為ませんでした" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                 rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "来ませんでした" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                 rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來ませんでした" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                 rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "ましょう" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "いましょう" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "きましょう" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-         "ぎましょう" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "しましょう" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "ちましょう" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-          "にましょう" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-         "びましょう" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-         "みましょう" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "りましょう" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じましょう" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "為ましょう" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "来ましょう" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來ましょう" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "れる" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[RuleType::V1],
-                 rules_out: &[RuleType::V1, RuleType::V5],
-            },
-        ],
-        "える" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[RuleType::V1],
-                 rules_out: &[RuleType::V5],
-            },
-        ],
-         "ける" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                 rules_in: &[RuleType::V1],
-                 rules_out: &[RuleType::V5],
-            },
-        ],
-         "げる" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                 rules_in: &[RuleType::V1],
-                 rules_out: &[RuleType::V5],
-            },
-        ],
-         "せる" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                 rules_in: &[RuleType::V1],
-                 rules_out: &[RuleType::V5],
-            },
-        ],
-         "てる" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "て",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Iru],
-            },
-        ],
-        "ねる" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "べる" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                 rules_in: &[RuleType::V1],
-                 rules_out: &[RuleType::V5],
-            },
-        ],
-         "める" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-         "これる" => &[
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "来れる" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                 rules_in: &[RuleType::V1],
-                 rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來れる" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-            "ざれる" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-          "ぜられる" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-          "せられる" => &[
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-         "為られる" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-       "よう" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "おう" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-         "こう" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ごう" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-          "とう" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "のう" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                 rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ぼう" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "もう" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "ろう" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じよう" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-        "しよう" => &[
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "為よう" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "りながら" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "きながら" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "しながら" => &[
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-        "ながら" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "らん" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-        "こよう" => &[
-            DeinflectionRule {
-                kana_out: "くる",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "来よう" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "來よう" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-        "かされる" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "がされる" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "たされる" => &[
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "なされる" => &[
-             DeinflectionRule {
-                kana_out: "ぬ",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-          "ばされる" => &[
-            DeinflectionRule {
-                kana_out: "ぶ",
-                 rules_in: &[RuleType::V1],
-                 rules_out: &[RuleType::V5],
-            },
-        ],
-         "まされる" => &[
-            DeinflectionRule {
-                kana_out: "む",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-          "らされる" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                 rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-          "わされる" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                 rules_in: &[RuleType::V1],
-                 rules_out: &[RuleType::V5],
-            },
-        ],
-            "とく" => &[
-            DeinflectionRule {
-                kana_out: "る",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V1],
-            },
-        ],
-            "いとく" => &[
-            DeinflectionRule {
-                kana_out: "く",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-             "いどく" => &[
-            DeinflectionRule {
-                kana_out: "ぐ",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-          "しとく" => &[
-            DeinflectionRule {
-                kana_out: "す",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-             DeinflectionRule {
-                kana_out: "する",
-                 rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-            "っとく" => &[
-            DeinflectionRule {
-                kana_out: "う",
-                 rules_in: &[RuleType::V5],
-                 rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "つ",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-             DeinflectionRule {
-                kana_out: "る",
-                 rules_in: &[RuleType::V5],
-                 rules_out: &[RuleType::V5],
-            },
-        ],
-            "んどく" => &[
-            DeinflectionRule {
-                kana_out: "ぬ",
-                 rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "ぶ",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-            DeinflectionRule {
-                kana_out: "む",
-                 rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::V5],
-            },
-        ],
-        "じとく" => &[
-            DeinflectionRule {
-                kana_out: "ずる",
-                 rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vz],
-            },
-        ],
-          "為とく" => &[
-            DeinflectionRule {
-                kana_out: "為る",
-                 rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-          "きとく" => &[
-            DeinflectionRule {
-                kana_out: "くる",
-                 rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-          "来とく" => &[
-            DeinflectionRule {
-                kana_out: "来る",
-                 rules_in: &[RuleType::V5],
-                 rules_out: &[RuleType::Vk],
-            },
-        ],
-          "來とく" => &[
-            DeinflectionRule {
-                kana_out: "來る",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Vk],
-            },
-        ],
-          "ている" => &[
-            DeinflectionRule {
-                kana_out: "て",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Iru],
-            },
-        ],
-           "ておる" => &[
-            DeinflectionRule {
-                kana_out: "て",
-                 rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Iru],
-            },
-        ],
-           "でいる" => &[
-            DeinflectionRule {
-                kana_out: "で",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Iru],
-            },
-        ],
-           "でおる" => &[
-            DeinflectionRule {
-                kana_out: "で",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Iru],
-            },
-        ],
-         "でる" => &[
-            DeinflectionRule {
-                kana_out: "で",
-                 rules_in: &[RuleType::V1],
-                 rules_out: &[RuleType::Iru],
-            },
-        ],
-         "とる" => &[
-            DeinflectionRule {
-                kana_out: "て",
-                rules_in: &[RuleType::V5],
-                rules_out: &[RuleType::Iru],
-            },
-        ],
-            "ないでいる" => &[
-             DeinflectionRule {
-                kana_out: "ない",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::AdjI],
-             },
-        ],
-           "しげ" => &[
-            DeinflectionRule {
-                kana_out: "しい",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-            "ねえ" => &[
-            DeinflectionRule {
-                kana_out: "ない",
-                 rules_in: &[],
-                 rules_out: &[RuleType::AdjI],
-            },
-        ],
-          "めえ" => &[
-            DeinflectionRule {
-                kana_out: "むい",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-            DeinflectionRule {
-                kana_out: "まい",
-                 rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-            "みい" => &[
-             DeinflectionRule {
-                kana_out: "むい",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-             },
-        ],
-           "ちぇえ" => &[
-             DeinflectionRule {
-                kana_out: "つい",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-             },
-            DeinflectionRule {
-                kana_out: "ちゃい",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-             },
-        ],
-          "ちい" => &[
-             DeinflectionRule {
-                kana_out: "つい",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-             },
-        ],
-         "せえ" => &[
-            DeinflectionRule {
-                kana_out: "すい",
-                 rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-            DeinflectionRule {
-                kana_out: "さい",
-                 rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-        "ええ" => &[
-              DeinflectionRule {
-                kana_out: "いい",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-            DeinflectionRule {
-                kana_out: "わい",
-                 rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-              DeinflectionRule {
-                kana_out: "よい",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-             },
-        ],
-           "いぇえ" => &[
-             DeinflectionRule {
-                kana_out: "よい",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-        "うぇえ" => &[
-            DeinflectionRule {
-                 kana_out: "わい",
-                 rules_in: &[],
-                 rules_out: &[RuleType::AdjI],
-            },
-        ],
-        "けえ" => &[
-            DeinflectionRule {
-                kana_out: "かい",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-         "げえ" => &[
-             DeinflectionRule {
-                kana_out: "がい",
-                 rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-             DeinflectionRule {
-                kana_out: "ごい",
-                 rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-             },
-        ],
-        "ぜえ" => &[
-             DeinflectionRule {
-                kana_out: "ずい",
-                rules_in: &[],
-                 rules_out: &[RuleType::AdjI],
-            },
-        ],
-         "っぜえ" => &[
-             DeinflectionRule {
-                kana_out: "ずい",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-         "れえ" => &[
-            DeinflectionRule {
-                kana_out: "らい",
-                 rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-             DeinflectionRule {
-                kana_out: "れい",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-             },
-        ],
-        "でえ" => &[
-             DeinflectionRule {
-                kana_out: "どい",
-                rules_in: &[],
-                 rules_out: &[RuleType::AdjI],
-             },
-        ],
-          "べえ" => &[
-            DeinflectionRule {
-                kana_out: "ばい",
-                rules_in: &[],
-                rules_out: &[RuleType::AdjI],
-            },
-        ],
-        "できる" => &[
-            DeinflectionRule {
-                kana_out: "する",
-                rules_in: &[RuleType::V1],
-                rules_out: &[RuleType::Vs],
-            },
-        ],
-    }
+        DeinflectionRule {
+            kana_out: "乞う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tari,
+        },
+    ],
+    "恋うたり" => &[
+        DeinflectionRule {
+            kana_out: "恋う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tari,
+        },
+    ],
+    "問うたり" => &[
+        DeinflectionRule {
+            kana_out: "問う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tari,
+        },
+    ],
+    "負うたり" => &[
+        DeinflectionRule {
+            kana_out: "負う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tari,
+        },
+    ],
+    "沿うたり" => &[
+        DeinflectionRule {
+            kana_out: "沿う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tari,
+        },
+    ],
+    "添うたり" => &[
+        DeinflectionRule {
+            kana_out: "添う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tari,
+        },
+    ],
+    "副うたり" => &[
+        DeinflectionRule {
+            kana_out: "副う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tari,
+        },
+    ],
+    "厭うたり" => &[
+        DeinflectionRule {
+            kana_out: "厭う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tari,
+        },
+    ],
+    "のたもうたり" => &[
+        DeinflectionRule {
+            kana_out: "のたまう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Tari,
+        },
+    ],
+    "くて" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "て" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Te,
+        },
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Imperative,
+        },
+        DeinflectionRule {
+            kana_out: "てる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "いて" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5K],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "いで" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "して" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Te,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "って" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5U],
+            kind: InflectionKind::Te,
+        },
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Te,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5R, RuleType::V5RI, RuleType::V5Aru],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "んで" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Te,
+        },
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Te,
+        },
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "じて" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "為て" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "きて" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "来て" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "來て" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "いって" => &[
+        DeinflectionRule {
+            kana_out: "いく",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "おうて" => &[
+        DeinflectionRule {
+            kana_out: "おう",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "こうて" => &[
+        DeinflectionRule {
+            kana_out: "こう",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "そうて" => &[
+        DeinflectionRule {
+            kana_out: "そう",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "とうて" => &[
+        DeinflectionRule {
+            kana_out: "とう",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "行って" => &[
+        DeinflectionRule {
+            kana_out: "行く",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "逝って" => &[
+        DeinflectionRule {
+            kana_out: "逝く",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "往って" => &[
+        DeinflectionRule {
+            kana_out: "往く",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "請うて" => &[
+        DeinflectionRule {
+            kana_out: "請う",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "乞うて" => &[
+        DeinflectionRule {
+            kana_out: "乞う",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "恋うて" => &[
+        DeinflectionRule {
+            kana_out: "恋う",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "問うて" => &[
+        DeinflectionRule {
+            kana_out: "問う",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "負うて" => &[
+        DeinflectionRule {
+            kana_out: "負う",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "沿うて" => &[
+        DeinflectionRule {
+            kana_out: "沿う",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "添うて" => &[
+        DeinflectionRule {
+            kana_out: "添う",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "副うて" => &[
+        DeinflectionRule {
+            kana_out: "副う",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "厭うて" => &[
+        DeinflectionRule {
+            kana_out: "厭う",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "のたもうて" => &[
+        DeinflectionRule {
+            kana_out: "のたまう",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "ず" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "かず" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "がず" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "さず" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "たず" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "なず" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "ばず" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "まず" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "らず" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI, RuleType::V5Aru],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "わず" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "ぜず" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "せず" => &[
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "為ず" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "こず" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "来ず" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "來ず" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "ぬ" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "かぬ" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "がぬ" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "さぬ" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "たぬ" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "なぬ" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "ばぬ" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "まぬ" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "らぬ" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI, RuleType::V5Aru],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "わぬ" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "ぜぬ" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "せぬ" => &[
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "為ぬ" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "こぬ" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "来ぬ" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "來ぬ" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Nu,
+        },
+    ],
+    "く" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Adverbial,
+        },
+    ],
+    "させる" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Causative,
+        },
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Causative,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "かせる" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "がせる" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "たせる" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "なせる" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "ばせる" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "ませる" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "らせる" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5R, RuleType::V5RI, RuleType::V5Aru],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "わせる" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "じさせる" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "ぜさせる" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "為せる" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "せさせる" => &[
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "為させる" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "こさせる" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "来させる" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "來させる" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Causative,
+        },
+    ],
+    "ろ" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "よ" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "え" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Imperative,
+        },
+        DeinflectionRule {
+            kana_out: "える",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "け" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Imperative,
+        },
+        DeinflectionRule {
+            kana_out: "ける",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "げ" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Imperative,
+        },
+        DeinflectionRule {
+            kana_out: "げる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ge,
+        },
+    ],
+    "せ" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Imperative,
+        },
+        DeinflectionRule {
+            kana_out: "せる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "ね" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Imperative,
+        },
+        DeinflectionRule {
+            kana_out: "ねる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "べ" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Imperative,
+        },
+        DeinflectionRule {
+            kana_out: "べる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "め" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Imperative,
+        },
+        DeinflectionRule {
+            kana_out: "める",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "れ" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::Imperative,
+        },
+        DeinflectionRule {
+            kana_out: "れる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "じろ" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "ぜよ" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "しろ" => &[
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "せよ" => &[
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "為ろ" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "為よ" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "こい" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "来い" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "來い" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "な" => &[
+        DeinflectionRule {
+            kana_out: "",
+            rules_in: &[],
+            rules_out: &[RuleType::V1, RuleType::V5K, RuleType::V5KS, RuleType::V5G, RuleType::V5S, RuleType::V5T, RuleType::V5N, RuleType::V5B, RuleType::V5M, RuleType::V5R, RuleType::V5RI, RuleType::V5Aru, RuleType::V5U, RuleType::V5US, RuleType::Vk, RuleType::Vs, RuleType::Vz],
+            kind: InflectionKind::ImperativeNegative,
+        },
+        DeinflectionRule {
+            kana_out: "",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjNa],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "い" => &[
+        DeinflectionRule {
+            kana_out: "いる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5Aru],
+            kind: InflectionKind::Imperative,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5Aru],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "き" => &[
+        DeinflectionRule {
+            kana_out: "きる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ki,
+        },
+    ],
+    "ぎ" => &[
+        DeinflectionRule {
+            kana_out: "ぎる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "じ" => &[
+        DeinflectionRule {
+            kana_out: "じる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "ぜ" => &[
+        DeinflectionRule {
+            kana_out: "ぜる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "ち" => &[
+        DeinflectionRule {
+            kana_out: "ちる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "で" => &[
+        DeinflectionRule {
+            kana_out: "でる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "に" => &[
+        DeinflectionRule {
+            kana_out: "にる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjNa],
+            kind: InflectionKind::Adverbial,
+        },
+    ],
+    "ひ" => &[
+        DeinflectionRule {
+            kana_out: "ひる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "び" => &[
+        DeinflectionRule {
+            kana_out: "びる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "へ" => &[
+        DeinflectionRule {
+            kana_out: "へる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "み" => &[
+        DeinflectionRule {
+            kana_out: "みる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Mi,
+        },
+    ],
+    "り" => &[
+        DeinflectionRule {
+            kana_out: "りる",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "し" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::MasuStem,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "来" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "來" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::MasuStem,
+        },
+    ],
+    "くない" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "ない" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "かない" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "がない" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "さない" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "たない" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "なない" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "ばない" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "まない" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "らない" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5R, RuleType::V5Aru],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "わない" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "じない" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "しない" => &[
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "為ない" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "こない" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "来ない" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "來ない" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "さ" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Sa,
+        },
+    ],
+    "かれる" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Passive,
+        },
+    ],
+    "がれる" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Passive,
+        },
+    ],
+    "される" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Passive,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Passive,
+        },
+    ],
+    "たれる" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Passive,
+        },
+    ],
+    "なれる" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Passive,
+        },
+    ],
+    "ばれる" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Passive,
+        },
+    ],
+    "まれる" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Passive,
+        },
+    ],
+    "われる" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Passive,
+        },
+    ],
+    "られる" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5R, RuleType::V5Aru, RuleType::V1],
+            kind: InflectionKind::PotentialOrPassive,
+        },
+    ],
+    "じされる" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Passive,
+        },
+    ],
+    "ぜされる" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Passive,
+        },
+    ],
+    "為れる" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Passive,
+        },
+    ],
+    "こられる" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::PotentialOrPassive,
+        },
+    ],
+    "来られる" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::PotentialOrPassive,
+        },
+    ],
+    "來られる" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::PotentialOrPassive,
+        },
+    ],
+    "かった" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "た" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "いた" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "いだ" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "した" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Past,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "った" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U],
+            kind: InflectionKind::Past,
+        },
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Past,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI, RuleType::V5Aru],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "んだ" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Past,
+        },
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Past,
+        },
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "じた" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "為た" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "きた" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "来た" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "來た" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "いった" => &[
+        DeinflectionRule {
+            kana_out: "いく",
+            rules_in: &[],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "おうた" => &[
+        DeinflectionRule {
+            kana_out: "おう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "こうた" => &[
+        DeinflectionRule {
+            kana_out: "こう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "そうた" => &[
+        DeinflectionRule {
+            kana_out: "そう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "とうた" => &[
+        DeinflectionRule {
+            kana_out: "とう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "行った" => &[
+        DeinflectionRule {
+            kana_out: "行く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "逝った" => &[
+        DeinflectionRule {
+            kana_out: "逝く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "往った" => &[
+        DeinflectionRule {
+            kana_out: "往く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "請うた" => &[
+        DeinflectionRule {
+            kana_out: "請う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "乞うた" => &[
+        DeinflectionRule {
+            kana_out: "乞う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "恋うた" => &[
+        DeinflectionRule {
+            kana_out: "恋う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "問うた" => &[
+        DeinflectionRule {
+            kana_out: "問う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "負うた" => &[
+        DeinflectionRule {
+            kana_out: "負う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "沿うた" => &[
+        DeinflectionRule {
+            kana_out: "沿う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "添うた" => &[
+        DeinflectionRule {
+            kana_out: "添う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "副うた" => &[
+        DeinflectionRule {
+            kana_out: "副う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "厭うた" => &[
+        DeinflectionRule {
+            kana_out: "厭う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "のたもうた" => &[
+        DeinflectionRule {
+            kana_out: "のたまう",
+            rules_in: &[],
+            rules_out: &[RuleType::V5US],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "ます" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "います" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Polite,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5Aru],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "きます" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Polite,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "ぎます" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "します" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Polite,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "ちます" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "にます" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "びます" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "みます" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "ります" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "じます" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "為ます" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "来ます" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "來ます" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "くありません" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "ません" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "いません" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::PoliteNegative,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5Aru],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "きません" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "ぎません" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "しません" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::PoliteNegative,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "ちません" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "にません" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "びません" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "みません" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "りません" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "じません" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "為ません" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "来ません" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "來ません" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+    "ました" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "いました" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::PolitePast,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5Aru],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "きました" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::PolitePast,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "ぎました" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "しました" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::PolitePast,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "ちました" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "にました" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "びました" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "みました" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "りました" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "じました" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "為ました" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "来ました" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "來ました" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "くありませんでした" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "ませんでした" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "いませんでした" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::PolitePastNegative,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5Aru],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "きませんでした" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::PolitePastNegative,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "ぎませんでした" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "しませんでした" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::PolitePastNegative,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "ちませんでした" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "にませんでした" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "びませんでした" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "みませんでした" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "りませんでした" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "じませんでした" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "為ませんでした" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "来ませんでした" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "來ませんでした" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::PolitePastNegative,
+        },
+    ],
+    "ましょう" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "いましょう" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::PoliteVolitional,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5Aru],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "きましょう" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::PoliteVolitional,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "ぎましょう" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "しましょう" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::PoliteVolitional,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "ちましょう" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "にましょう" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "びましょう" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "みましょう" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "りましょう" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "じましょう" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "為ましょう" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "来ましょう" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "來ましょう" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "れる" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V1, RuleType::V5R, RuleType::V5Aru],
+            kind: InflectionKind::Potential,
+        },
+    ],
+    "える" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Potential,
+        },
+    ],
+    "ける" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Potential,
+        },
+    ],
+    "げる" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Potential,
+        },
+    ],
+    "せる" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Potential,
+        },
+    ],
+    "てる" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Potential,
+        },
+    ],
+    "ねる" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Potential,
+        },
+    ],
+    "べる" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Potential,
+        },
+    ],
+    "める" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Potential,
+        },
+    ],
+    "これる" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Potential,
+        },
+    ],
+    "来れる" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Potential,
+        },
+    ],
+    "來れる" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Potential,
+        },
+    ],
+    "ざれる" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::PotentialOrPassive,
+        },
+    ],
+    "ぜられる" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::PotentialOrPassive,
+        },
+    ],
+    "せられる" => &[
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::PotentialOrPassive,
+        },
+    ],
+    "為られる" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::PotentialOrPassive,
+        },
+    ],
+    "よう" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "おう" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "こう" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "ごう" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "とう" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "のう" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "ぼう" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "もう" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "ろう" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI, RuleType::V5Aru],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "じよう" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "しよう" => &[
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "為よう" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "りながら" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Nagara,
+        },
+    ],
+    "きながら" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Nagara,
+        },
+    ],
+    "しながら" => &[
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Nagara,
+        },
+    ],
+    "ながら" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Nagara,
+        },
+    ],
+    "こよう" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "来よう" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "來よう" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "かされる" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::CausativePassive,
+        },
+    ],
+    "がされる" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::CausativePassive,
+        },
+    ],
+    "たされる" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::CausativePassive,
+        },
+    ],
+    "なされる" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::CausativePassive,
+        },
+    ],
+    "ばされる" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::CausativePassive,
+        },
+    ],
+    "まされる" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::CausativePassive,
+        },
+    ],
+    "らされる" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5R, RuleType::V5RI, RuleType::V5Aru],
+            kind: InflectionKind::CausativePassive,
+        },
+    ],
+    "わされる" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::CausativePassive,
+        },
+    ],
+    "ている" => &[
+        DeinflectionRule {
+            kana_out: "て",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Progressive,
+        },
+    ],
+    "ておる" => &[
+        DeinflectionRule {
+            kana_out: "て",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Progressive,
+        },
+    ],
+    "でいる" => &[
+        DeinflectionRule {
+            kana_out: "で",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Progressive,
+        },
+    ],
+    "でおる" => &[
+        DeinflectionRule {
+            kana_out: "で",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Progressive,
+        },
+    ],
+    "ないでいる" => &[
+        DeinflectionRule {
+            kana_out: "ない",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::NaideIru,
+        },
+    ],
+    "しげ" => &[
+        DeinflectionRule {
+            kana_out: "しい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ge,
+        },
+    ],
+    "できる" => &[
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Potential,
+        },
+    ],
+    "だ" => &[
+        DeinflectionRule {
+            kana_out: "",
+            rules_in: &[RuleType::Copula],
+            rules_out: &[RuleType::AdjNa],
+            kind: InflectionKind::Copula,
+        },
+    ],
+    "だった" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[RuleType::Copula],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "だったろう" => &[
+        DeinflectionRule {
+            kana_out: "だった",
+            rules_in: &[],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "だったら" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Tara,
+        },
+    ],
+    "だって" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "です" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Polite,
+        },
+    ],
+    "でした" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::PolitePast,
+        },
+    ],
+    "でしたら" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Tara,
+        },
+    ],
+    "じゃない" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "ではない" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "でない" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "だろう" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Volitional,
+        },
+    ],
+    "でしょう" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::PoliteVolitional,
+        },
+    ],
+    "なら" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Nara,
+        },
+    ],
+    "である" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[RuleType::V5RI],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Dearu,
+        },
+    ],
+    "ではある" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[RuleType::V5RI],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Dearu,
+        },
+    ],
+    "じゃある" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[RuleType::V5RI],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Dearu,
+        },
+    ],
+    "でござる" => &[
+        DeinflectionRule {
+            kana_out: "だ",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::Copula],
+            kind: InflectionKind::Dearu,
+        },
+    ],
+    "になる" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Honorific,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Honorific,
+        },
+    ],
+    "いになる" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Honorific,
+        },
+    ],
+    "きになる" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Honorific,
+        },
+    ],
+    "ぎになる" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Honorific,
+        },
+    ],
+    "しになる" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Honorific,
+        },
+    ],
+    "ちになる" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Honorific,
+        },
+    ],
+    "にになる" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Honorific,
+        },
+    ],
+    "びになる" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Honorific,
+        },
+    ],
+    "みになる" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Honorific,
+        },
+    ],
+    "りになる" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::Honorific,
+        },
+    ],
+    "する" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vs],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Humble,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::Vs],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "いする" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::Vs],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "きする" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::Vs],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "ぎする" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::Vs],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "しする" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::Vs],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "ちする" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::Vs],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "にする" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::Vs],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "びする" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::Vs],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "みする" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::Vs],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "りする" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vs],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "いたす" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V5S],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Humble,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::V5S],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "いいたす" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::V5S],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "きいたす" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::V5S],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "ぎいたす" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::V5S],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "しいたす" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::V5S],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "ちいたす" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::V5S],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "にいたす" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::V5S],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "びいたす" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::V5S],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "みいたす" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::V5S],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "りいたす" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V5S],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::Humble,
+        },
+    ],
+    "なさる" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Nasaru,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Nasaru,
+        },
+    ],
+    "いなさる" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::V5U, RuleType::V5US],
+            kind: InflectionKind::Nasaru,
+        },
+    ],
+    "きなさる" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Nasaru,
+        },
+    ],
+    "ぎなさる" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Nasaru,
+        },
+    ],
+    "しなさる" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Nasaru,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Nasaru,
+        },
+    ],
+    "ちなさる" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Nasaru,
+        },
+    ],
+    "になさる" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Nasaru,
+        },
+    ],
+    "びなさる" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Nasaru,
+        },
+    ],
+    "みなさる" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Nasaru,
+        },
+    ],
+    "りなさる" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::V5R, RuleType::V5RI],
+            kind: InflectionKind::Nasaru,
+        },
+    ],
+    "てくださる" => &[
+        DeinflectionRule {
+            kana_out: "て",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Kudasaru,
+        },
+    ],
+    "でくださる" => &[
+        DeinflectionRule {
+            kana_out: "で",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Kudasaru,
+        },
+    ],
+    "ていらっしゃる" => &[
+        DeinflectionRule {
+            kana_out: "て",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Irassharu,
+        },
+    ],
+    "でいらっしゃる" => &[
+        DeinflectionRule {
+            kana_out: "で",
+            rules_in: &[RuleType::V5Aru],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Irassharu,
+        },
+    ],
+    "ていただく" => &[
+        DeinflectionRule {
+            kana_out: "て",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Itadaku,
+        },
+    ],
+    "でいただく" => &[
+        DeinflectionRule {
+            kana_out: "で",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Itadaku,
+        },
+    ],
+    "いませ" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5Aru],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "がる" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Garu,
+        },
+    ],
+    "さそう" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "さすぎる" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Sugiru,
+        },
+    ],
+    "よさそう" => &[
+        DeinflectionRule {
+            kana_out: "いい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Sou,
+        },
+    ],
+    "よさすぎる" => &[
+        DeinflectionRule {
+            kana_out: "いい",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Sugiru,
+        },
+    ],
+    "よければ" => &[
+        DeinflectionRule {
+            kana_out: "いい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "よすぎる" => &[
+        DeinflectionRule {
+            kana_out: "いい",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Sugiru,
+        },
+    ],
+    "よかったら" => &[
+        DeinflectionRule {
+            kana_out: "いい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Tara,
+        },
+    ],
+    "よかったり" => &[
+        DeinflectionRule {
+            kana_out: "いい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Tari,
+        },
+    ],
+    "よくて" => &[
+        DeinflectionRule {
+            kana_out: "いい",
+            rules_in: &[RuleType::Iru],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "よく" => &[
+        DeinflectionRule {
+            kana_out: "いい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Adverbial,
+        },
+    ],
+    "よくない" => &[
+        DeinflectionRule {
+            kana_out: "いい",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Negative,
+        },
+    ],
+    "よさ" => &[
+        DeinflectionRule {
+            kana_out: "いい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Sa,
+        },
+    ],
+    "よかった" => &[
+        DeinflectionRule {
+            kana_out: "いい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Past,
+        },
+    ],
+    "よくありません" => &[
+        DeinflectionRule {
+            kana_out: "いい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::PoliteNegative,
+        },
+    ],
+}
+
+// Contractions of casual speech, e.g. 食べてる, 見とく, 行かなきゃ or 知らねえ.
+// They are looked up after the rules above unless disabled with `Deinflector::with_colloquial`.
+deinflection_rules! {
+    get_colloquial_rules, COLLOQUIAL_RULES;
+    "ちゃう" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Chau,
+        },
+    ],
+    "いじゃう" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Chau,
+        },
+    ],
+    "いちゃう" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5K],
+            kind: InflectionKind::Chau,
+        },
+    ],
+    "しちゃう" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Chau,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Chau,
+        },
+    ],
+    "っちゃう" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5U],
+            kind: InflectionKind::Chau,
+        },
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Chau,
+        },
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Chau,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5R, RuleType::V5RI, RuleType::V5Aru],
+            kind: InflectionKind::Chau,
+        },
+    ],
+    "んじゃう" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Chau,
+        },
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Chau,
+        },
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Chau,
+        },
+    ],
+    "じちゃう" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Chau,
+        },
+    ],
+    "為ちゃう" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Chau,
+        },
+    ],
+    "きちゃう" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Chau,
+        },
+    ],
+    "来ちゃう" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Chau,
+        },
+    ],
+    "來ちゃう" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Chau,
+        },
+    ],
+    "ちまう" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Chimau,
+        },
+    ],
+    "いじまう" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Chimau,
+        },
+    ],
+    "いちまう" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5K],
+            kind: InflectionKind::Chimau,
+        },
+    ],
+    "しちまう" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Chimau,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Chimau,
+        },
+    ],
+    "っちまう" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5U],
+            kind: InflectionKind::Chimau,
+        },
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Chimau,
+        },
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Chimau,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5R, RuleType::V5RI, RuleType::V5Aru],
+            kind: InflectionKind::Chimau,
+        },
+    ],
+    "んじまう" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Chimau,
+        },
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Chimau,
+        },
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Chimau,
+        },
+    ],
+    "じちまう" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Chimau,
+        },
+    ],
+    "為ちまう" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Chimau,
+        },
+    ],
+    "きちまう" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Chimau,
+        },
+    ],
+    "来ちまう" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Chimau,
+        },
+    ],
+    "來ちまう" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::V5U],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Chimau,
+        },
+    ],
+    "てる" => &[
+        DeinflectionRule {
+            kana_out: "て",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Progressive,
+        },
+    ],
+    "らん" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::NSlang,
+        },
+    ],
+    "とく" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Toku,
+        },
+    ],
+    "いとく" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::V5K],
+            kind: InflectionKind::Toku,
+        },
+    ],
+    "いどく" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Toku,
+        },
+    ],
+    "しとく" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Toku,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Toku,
+        },
+    ],
+    "っとく" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::V5U],
+            kind: InflectionKind::Toku,
+        },
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Toku,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::V5R, RuleType::V5RI, RuleType::V5Aru],
+            kind: InflectionKind::Toku,
+        },
+    ],
+    "んどく" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Toku,
+        },
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Toku,
+        },
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Toku,
+        },
+    ],
+    "じとく" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Toku,
+        },
+    ],
+    "為とく" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Toku,
+        },
+    ],
+    "きとく" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Toku,
+        },
+    ],
+    "来とく" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Toku,
+        },
+    ],
+    "來とく" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::V5K],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Toku,
+        },
+    ],
+    "でる" => &[
+        DeinflectionRule {
+            kana_out: "で",
+            rules_in: &[RuleType::V1],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Progressive,
+        },
+    ],
+    "とる" => &[
+        DeinflectionRule {
+            kana_out: "て",
+            rules_in: &[RuleType::V5R],
+            rules_out: &[RuleType::Iru],
+            kind: InflectionKind::Progressive,
+        },
+    ],
+    "ねえ" => &[
+        DeinflectionRule {
+            kana_out: "ない",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "めえ" => &[
+        DeinflectionRule {
+            kana_out: "むい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+        DeinflectionRule {
+            kana_out: "まい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "みい" => &[
+        DeinflectionRule {
+            kana_out: "むい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "ちぇえ" => &[
+        DeinflectionRule {
+            kana_out: "つい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+        DeinflectionRule {
+            kana_out: "ちゃい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "ちい" => &[
+        DeinflectionRule {
+            kana_out: "つい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "せえ" => &[
+        DeinflectionRule {
+            kana_out: "すい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+        DeinflectionRule {
+            kana_out: "さい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "ええ" => &[
+        DeinflectionRule {
+            kana_out: "いい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+        DeinflectionRule {
+            kana_out: "わい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+        DeinflectionRule {
+            kana_out: "よい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "いぇえ" => &[
+        DeinflectionRule {
+            kana_out: "よい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "うぇえ" => &[
+        DeinflectionRule {
+            kana_out: "わい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "けえ" => &[
+        DeinflectionRule {
+            kana_out: "かい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "げえ" => &[
+        DeinflectionRule {
+            kana_out: "がい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+        DeinflectionRule {
+            kana_out: "ごい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "ぜえ" => &[
+        DeinflectionRule {
+            kana_out: "ずい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "っぜえ" => &[
+        DeinflectionRule {
+            kana_out: "ずい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "れえ" => &[
+        DeinflectionRule {
+            kana_out: "らい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+        DeinflectionRule {
+            kana_out: "れい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "でえ" => &[
+        DeinflectionRule {
+            kana_out: "どい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "べえ" => &[
+        DeinflectionRule {
+            kana_out: "ばい",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Ee,
+        },
+    ],
+    "ちゃ" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Cha,
+        },
+    ],
+    "いじゃ" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Cha,
+        },
+    ],
+    "いちゃ" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K],
+            kind: InflectionKind::Cha,
+        },
+    ],
+    "しちゃ" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Cha,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Cha,
+        },
+    ],
+    "っちゃ" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U],
+            kind: InflectionKind::Cha,
+        },
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5KS],
+            kind: InflectionKind::Cha,
+        },
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Cha,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V5RI, RuleType::V5Aru],
+            kind: InflectionKind::Cha,
+        },
+    ],
+    "んじゃ" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5N],
+            kind: InflectionKind::Cha,
+        },
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Cha,
+        },
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Cha,
+        },
+    ],
+    "じちゃ" => &[
+        DeinflectionRule {
+            kana_out: "ずる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vz],
+            kind: InflectionKind::Cha,
+        },
+    ],
+    "為ちゃ" => &[
+        DeinflectionRule {
+            kana_out: "為る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Cha,
+        },
+    ],
+    "きちゃ" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Cha,
+        },
+    ],
+    "来ちゃ" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Cha,
+        },
+    ],
+    "來ちゃ" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Cha,
+        },
+    ],
+    "くちゃ" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Cha,
+        },
+    ],
+    "けりゃ" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Kya,
+        },
+    ],
+    "きゃ" => &[
+        DeinflectionRule {
+            kana_out: "い",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjI],
+            kind: InflectionKind::Kya,
+        },
+    ],
+    "んない" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::AdjI],
+            rules_out: &[RuleType::V5R, RuleType::V5Aru],
+            kind: InflectionKind::Negative,
+        },
+    ],
+}
+
+// Classical (bungo) auxiliaries and verb classes, e.g. 知らざりき, 受くる or 行くべし.
+// They are only looked up if enabled with `Deinflector::with_classical`.
+deinflection_rules! {
+    get_classical_rules, CLASSICAL_RULES;
+    "かざり" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "かむ" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "かん" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "きき" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "きし" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "きしか" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V5K, RuleType::V5KS, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "きけり" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5K, RuleType::V5KS, RuleType::V2K],
+            kind: InflectionKind::Keri,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "きたり" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5K, RuleType::V5KS, RuleType::V2K],
+            kind: InflectionKind::Perfective,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "けり" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5K, RuleType::V5KS],
+            kind: InflectionKind::Perfective,
+        },
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "くべし" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::V5K, RuleType::V5KS, RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Beshi,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "くなり" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5K, RuleType::V5KS, RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "がざり" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "がむ" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "がん" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ぎき" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ぎし" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ぎしか" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5G, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ぎけり" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5G, RuleType::V2K],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "ぎたり" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5G, RuleType::V2K],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "げり" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5G],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "ぐべし" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::V5G, RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "ぐなり" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5G, RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "さざり" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "さむ" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "さん" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "しき" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::ClassicalPast,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::ClassicalPast,
+        },
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjShiku],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "しし" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::ClassicalPast,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ししか" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::ClassicalPast,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "しけり" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Keri,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "したり" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Perfective,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "せり" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5S],
+            kind: InflectionKind::Perfective,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "すべし" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::V5S, RuleType::V2S],
+            kind: InflectionKind::Beshi,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "すなり" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5S, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "たざり" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "たむ" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "たん" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ちき" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ちし" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ちしか" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5T, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ちけり" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5T, RuleType::V2K],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "ちたり" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5T, RuleType::V2K],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "てり" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5T],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "つべし" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::V5T, RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "つなり" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5T, RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "はず" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "はざり" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5U],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "はむ" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "はん" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ひき" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ひし" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ひしか" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5U, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ひけり" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5U, RuleType::V2K],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "ひたり" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5U, RuleType::V2K],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "へり" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5U],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "ふべし" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::V5U, RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "ふなり" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5U, RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "ばざり" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "ばむ" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ばん" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "びき" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "びし" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "びしか" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V5B, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "びけり" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5B, RuleType::V2K],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "びたり" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5B, RuleType::V2K],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "べり" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5B],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "ぶべし" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::V5B, RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "ぶなり" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5B, RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "まざり" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "まむ" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "まん" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "みき" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "みし" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "みしか" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V5M, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "みけり" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5M, RuleType::V2K],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "みたり" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5M, RuleType::V2K],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "めり" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5M],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "むべし" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::V5M, RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "むなり" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5M, RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "らざり" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5R],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "らむ" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "らん" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "りき" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "りし" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "りしか" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V5R, RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "りけり" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5R, RuleType::V2K],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "りたり" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5R, RuleType::V2K],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "れり" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5R],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "るべし" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::V5R, RuleType::V2K, RuleType::V2S, RuleType::V1],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "るなり" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V5R, RuleType::V2K, RuleType::V2S, RuleType::V1],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "きず" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "きざり" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "きむ" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "きん" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "きて" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "きよ" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "くる" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "くれ" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "くれば" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "くるなり" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "ぎず" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "ぎざり" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "ぎむ" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ぎん" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ぎて" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "ぎよ" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "ぐる" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "ぐれ" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "ぐれば" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "ぐるなり" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "ちず" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "ちざり" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "ちむ" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ちん" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ちて" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "ちよ" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "つる" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "つれ" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "つれば" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "つるなり" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "ぢず" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "ぢざり" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "ぢむ" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ぢん" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ぢき" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ぢし" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ぢしか" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ぢけり" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "ぢたり" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "ぢて" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "ぢよ" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "づる" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "づれ" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "づれば" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "づべし" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "づなり" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "づるなり" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "ひず" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "ひざり" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "ひむ" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ひん" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ひて" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "ひよ" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "ふる" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "ふれ" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "ふれば" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "ふるなり" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "びず" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "びざり" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "びむ" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "びん" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "びて" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "びよ" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "ぶる" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "ぶれ" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "ぶれば" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "ぶるなり" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "みず" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "みざり" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "みむ" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "みん" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "みて" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "みよ" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "むる" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "むれ" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "むれば" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "むるなり" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "いず" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "いざり" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "いむ" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "いん" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "いき" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "いし" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "いしか" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "いけり" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "いたり" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "いて" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "いよ" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "ゆる" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "ゆれ" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "ゆれば" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "ゆべし" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "ゆなり" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "ゆるなり" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "りず" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "りざり" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "りむ" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "りん" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "りて" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "りよ" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "るる" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "るれ" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "るれば" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "るるなり" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2K, RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "けず" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "けざり" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "けむ" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "けん" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "けき" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "けし" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "けしか" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "けけり" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "けたり" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "けて" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "けよ" => &[
+        DeinflectionRule {
+            kana_out: "く",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "げず" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "げざり" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "げむ" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "げん" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "げき" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "げし" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "げしか" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "げけり" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "げたり" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "げて" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "げよ" => &[
+        DeinflectionRule {
+            kana_out: "ぐ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "せず" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "せざり" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zaru,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "せむ" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "せん" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "せき" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "せし" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "せしか" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "せけり" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "せたり" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "せて" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "せよ" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "する" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "すれ" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "すれば" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "するなり" => &[
+        DeinflectionRule {
+            kana_out: "す",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+        DeinflectionRule {
+            kana_out: "する",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vs],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "ぜず" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "ぜざり" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "ぜむ" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ぜん" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ぜき" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ぜし" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ぜしか" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ぜけり" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "ぜたり" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "ぜて" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "ぜよ" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "ずる" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "ずれ" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "ずれば" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "ずべし" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "ずなり" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "ずるなり" => &[
+        DeinflectionRule {
+            kana_out: "ず",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "てず" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "てざり" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "てむ" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "てん" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "てき" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "てし" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "てしか" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "てけり" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "てたり" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "てて" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "てよ" => &[
+        DeinflectionRule {
+            kana_out: "つ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "でず" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "でざり" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "でむ" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "でん" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "でき" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "でし" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "でしか" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "でけり" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "でたり" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "でて" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "でよ" => &[
+        DeinflectionRule {
+            kana_out: "づ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "ねず" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "ねざり" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "ねむ" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ねん" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ねき" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ねし" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ねしか" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ねけり" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "ねたり" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "ねて" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "ねよ" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "ぬる" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S, RuleType::Vn],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "ぬれ" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S, RuleType::Vn],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "ぬれば" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S, RuleType::Vn],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "ぬべし" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::V2S, RuleType::Vn],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "ぬなり" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S, RuleType::Vn],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "ぬるなり" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S, RuleType::Vn],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "へず" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "へざり" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "へむ" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "へん" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "へき" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "へし" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "へしか" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "へけり" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "へたり" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "へて" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "へよ" => &[
+        DeinflectionRule {
+            kana_out: "ふ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "べず" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "べざり" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "べむ" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "べん" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "べき" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "べし" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "べしか" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "べけり" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "べたり" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "べて" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "べよ" => &[
+        DeinflectionRule {
+            kana_out: "ぶ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "めず" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "めざり" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "めむ" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "めん" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "めき" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "めし" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "めしか" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "めけり" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "めたり" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "めて" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "めよ" => &[
+        DeinflectionRule {
+            kana_out: "む",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "えず" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zu,
+        },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "えざり" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zaru,
+        },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "えむ" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "えん" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "えき" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "えし" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "えしか" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "えけり" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Keri,
+        },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "えたり" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Perfective,
+        },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "えて" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Te,
+        },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "えよ" => &[
+        DeinflectionRule {
+            kana_out: "ゆ",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Imperative,
+        },
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "れず" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "れざり" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "れむ" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "れん" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "れき" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "れし" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "れしか" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "れけり" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "れたり" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "れて" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "れよ" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "うる" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "うれ" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "うれば" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "うべし" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "うなり" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "うるなり" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "ゑず" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "ゑざり" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "ゑむ" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ゑん" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ゑき" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ゑし" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ゑしか" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ゑけり" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "ゑたり" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "ゑて" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "ゑよ" => &[
+        DeinflectionRule {
+            kana_out: "う",
+            rules_in: &[],
+            rules_out: &[RuleType::V2S],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "なず" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::Vn],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "なざり" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vn],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "なむ" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::Vn],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "なん" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::Vn],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "にき" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::Vn],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "にし" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::Vn],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "にしか" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::Vn],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "にけり" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vn],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "にたり" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vn],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "にて" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::Vn],
+            kind: InflectionKind::Te,
+        },
+    ],
+    "によ" => &[
+        DeinflectionRule {
+            kana_out: "ぬ",
+            rules_in: &[],
+            rules_out: &[RuleType::Vn],
+            kind: InflectionKind::Imperative,
+        },
+    ],
+    "ざり" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "む" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ん" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "き" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::ClassicalPast,
+        },
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjKu],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "し" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "しか" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "たり" => &[
+        DeinflectionRule {
+            kana_out: "る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::V1],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "こざり" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "こむ" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "こん" => &[
+        DeinflectionRule {
+            kana_out: "くる",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "来ざり" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "来む" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "来ん" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "来き" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "来し" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "来しか" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "来けり" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "来たり" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "来べし" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "来なり" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "来るなり" => &[
+        DeinflectionRule {
+            kana_out: "来る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "來ざり" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "來む" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "來ん" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "來き" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "來し" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "來しか" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "來けり" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "來たり" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "來べし" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "來なり" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "來るなり" => &[
+        DeinflectionRule {
+            kana_out: "來る",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vk],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "あらず" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "あらざり" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "あらむ" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "あらん" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ありき" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ありし" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ありしか" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ありけり" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "ありたり" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "ある" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "あれ" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "あれば" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "あるべし" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "ありなり" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "あるなり" => &[
+        DeinflectionRule {
+            kana_out: "あり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "をらず" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "をらざり" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "をらむ" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "をらん" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "をりき" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "をりし" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "をりしか" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "をりけり" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "をりたり" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "をる" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "をれ" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "をれば" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "をるべし" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "をりなり" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "をるなり" => &[
+        DeinflectionRule {
+            kana_out: "をり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "侍らず" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "侍らざり" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "侍らむ" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "侍らん" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "侍りき" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "侍りし" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "侍りしか" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "侍りけり" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "侍りたり" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "侍る" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "侍れ" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "侍れば" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "侍るべし" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "侍りなり" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "侍るなり" => &[
+        DeinflectionRule {
+            kana_out: "侍り",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "はべらず" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "はべらざり" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "はべらむ" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "はべらん" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "はべりき" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "はべりし" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "はべりしか" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "はべりけり" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "はべりたり" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "はべる" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "はべれ" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "はべれば" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "はべるべし" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "はべりなり" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "はべるなり" => &[
+        DeinflectionRule {
+            kana_out: "はべり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "ざらず" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "ざらざり" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "ざらむ" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ざらん" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ざりき" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ざりし" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ざりしか" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "ざりけり" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "ざりたり" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "ざる" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "ざれ" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "ざれば" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "ざるべし" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "ざりなり" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "ざるなり" => &[
+        DeinflectionRule {
+            kana_out: "ざり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "たらず" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "たらざり" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "たらむ" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "たらん" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "たりき" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "たりし" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "たりしか" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "たりけり" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "たりたり" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "たる" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "たれ" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "たれば" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "たるべし" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "たりなり" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "たるなり" => &[
+        DeinflectionRule {
+            kana_out: "たり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "けらず" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "けらざり" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "けらむ" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "けらん" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "けりき" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "けりし" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "けりしか" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "けりけり" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "けりたり" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "ける" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "けれ" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Realis,
+        },
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjKu],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "ければ" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Ba,
+        },
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjKu],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "けるべし" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "けりなり" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "けるなり" => &[
+        DeinflectionRule {
+            kana_out: "けり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "ならず" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "ならざり" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Zaru,
+        },
+    ],
+    "ならむ" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "ならん" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Mu,
+        },
+    ],
+    "なりき" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "なりし" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "なりしか" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "なりけり" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "なりたり" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Perfective,
+        },
+    ],
+    "なる" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Attributive,
+        },
+    ],
+    "なれ" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "なれば" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "なるべし" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "なりなり" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "なるなり" => &[
+        DeinflectionRule {
+            kana_out: "なり",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::Vr],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "なり" => &[
+        DeinflectionRule {
+            kana_out: "",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::AdjNa],
+            kind: InflectionKind::Nari,
+        },
+    ],
+    "く" => &[
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjKu],
+            kind: InflectionKind::Adverbial,
+        },
+    ],
+    "からず" => &[
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjKu],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "かりき" => &[
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjKu],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "かりけり" => &[
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::AdjKu],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "かるべし" => &[
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::AdjKu],
+            kind: InflectionKind::Beshi,
+        },
+    ],
+    "しく" => &[
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjShiku],
+            kind: InflectionKind::Adverbial,
+        },
+    ],
+    "しけれ" => &[
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjShiku],
+            kind: InflectionKind::Realis,
+        },
+    ],
+    "しければ" => &[
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjShiku],
+            kind: InflectionKind::Ba,
+        },
+    ],
+    "しからず" => &[
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjShiku],
+            kind: InflectionKind::Zu,
+        },
+    ],
+    "しかりき" => &[
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[],
+            rules_out: &[RuleType::AdjShiku],
+            kind: InflectionKind::ClassicalPast,
+        },
+    ],
+    "しかりけり" => &[
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[RuleType::Vr],
+            rules_out: &[RuleType::AdjShiku],
+            kind: InflectionKind::Keri,
+        },
+    ],
+    "しかるべし" => &[
+        DeinflectionRule {
+            kana_out: "し",
+            rules_in: &[RuleType::AdjKu],
+            rules_out: &[RuleType::AdjShiku],
+            kind: InflectionKind::Beshi,
+        },
+    ],
 }
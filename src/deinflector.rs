@@ -0,0 +1,524 @@
+use crate::deinflect::{
+    capped_suffixes, deinflect_with_rules, strip_honorific_prefix, Deinflection, DeinflectionIter,
+    RuleType,
+};
+use crate::kata_to_hira::push_kata_to_hira;
+use crate::lexicon::{self, Lexicon};
+use crate::normalize::{normalize, normalized_spellings, OffsetMap};
+use crate::rank::{self, RankedDeinflection};
+use crate::rule_set::{RuleSet, Rules};
+use crate::scan::{self, ScanMatch};
+use fxhash::FxHashSet;
+use std::borrow::Cow;
+
+/// Returns a bit set of `types`, so that they can be stored without borrowing the rules
+#[inline]
+fn type_mask(types: &[RuleType]) -> u64 {
+    types.iter().fold(0, |mask, &t| mask | 1 << t as u64)
+}
+
+/// A candidate of [`Deinflector::deinflect_into`], whose word is stored in the arena
+#[derive(Debug, Clone)]
+struct Node {
+    start: usize,
+    end: usize,
+    types: u64,
+}
+
+/// Buffers that are reused by [`Deinflector::deinflect_into`]
+#[derive(Debug, Clone, Default)]
+struct Buffers {
+    /// The words of all candidates, back to back
+    arena: String,
+    nodes: Vec<Node>,
+    /// The word that is currently being deinflected
+    current: String,
+    seen: FxHashSet<usize>,
+}
+
+/// Deinflects words with a given set of rules.
+/// [`Deinflector::new`] uses the built-in rules just like [`deinflect`](crate::deinflect) does,
+/// [`Deinflector::with_rules`] uses a [`RuleSet`] that was built at runtime.
+///
+/// With [`Deinflector::with_normalization`], the input is [`normalize`](crate::normalize)d first.
+///
+/// A deinflector also owns buffers that [`Deinflector::deinflect_into`] reuses between calls,
+/// so it should be kept around when deinflecting many words.
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::{Deinflector, RuleSet};
+/// let deinflector = Deinflector::with_rules(RuleSet::builtin());
+/// assert!(deinflector.deinflect("食べた").iter().any(|w| w == "食べる"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Deinflector {
+    rules: Rules,
+    normalize: bool,
+    buffers: Buffers,
+}
+
+impl Deinflector {
+    /// Creates a deinflector that uses the built-in rules
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a deinflector that uses `rules` instead of the built-in rules
+    pub fn with_rules(rules: RuleSet) -> Self {
+        Self {
+            rules: Rules::Custom(rules),
+            ..Self::default()
+        }
+    }
+
+    /// Sets whether the rules for colloquial contractions like 食べてる, 見とく, 行かなきゃ or
+    /// 知らねえ are applied, which they are by default.
+    /// Disabling them avoids spurious candidates when processing formal text.
+    /// For a deinflector with custom rules, enabling them adds the built-in colloquial rules
+    /// to its rule set like [`RuleSet::add_colloquial`].
+    ///
+    /// # Examples
+    /// ```
+    /// use jp_deinflector::Deinflector;
+    /// let formal = Deinflector::new().with_colloquial(false);
+    /// assert!(!formal.deinflect("行かなきゃ").iter().any(|w| w == "行く"));
+    /// assert!(formal.deinflect("行かなければ").iter().any(|w| w == "行く"));
+    /// ```
+    pub fn with_colloquial(mut self, enabled: bool) -> Self {
+        match &mut self.rules {
+            Rules::Builtin { colloquial, .. } => *colloquial = enabled,
+            Rules::Custom(rule_set) if enabled => rule_set.add_colloquial(),
+            Rules::Custom(rule_set) => rule_set.remove_colloquial(),
+        }
+        self
+    }
+
+    /// Sets whether the rules for classical (bungo) Japanese are applied, e.g. to read old
+    /// novels or song lyrics. They cover the classical auxiliaries (知らざりき, 行きけり, 行くべし,
+    /// 咲きたり, ...), nidan, nahen and rahen verbs and ku/shiku adjectives.
+    /// This is disabled by default, since these rules produce many spurious candidates
+    /// for modern text.
+    ///
+    /// # Examples
+    /// ```
+    /// use jp_deinflector::{Deinflector, RuleType};
+    /// let deinflector = Deinflector::new().with_classical(true);
+    /// let okuru = deinflector.deinflect_detailed("起くる");
+    /// assert!(okuru.iter().any(|d| d.word == "起く" && d.types.contains(&RuleType::V2K)));
+    /// assert!(deinflector.deinflect("知らざりき").iter().any(|w| w == "知る"));
+    /// ```
+    pub fn with_classical(mut self, enabled: bool) -> Self {
+        match &mut self.rules {
+            Rules::Builtin { classical, .. } => *classical = enabled,
+            Rules::Custom(rule_set) if enabled => rule_set.add_classical(),
+            Rules::Custom(rule_set) => rule_set.remove_classical(),
+        }
+        self
+    }
+
+    /// Sets whether words are [`normalize`](crate::normalize)d before they are deinflected,
+    /// so that e.g. half-width katakana and prolonged sound marks are handled.
+    /// Since ー after お-row and え-row kana can also stand for the vowel itself, like in すげー,
+    /// words containing it are deinflected in that spelling as well, whose results follow the
+    /// ones of the normalized spelling.
+    /// The [`inflection_span`](Deinflection::inflection_span)s of the results still refer to
+    /// the word that was passed in.
+    /// This is disabled by default.
+    ///
+    /// # Examples
+    /// ```
+    /// use jp_deinflector::Deinflector;
+    /// let deinflector = Deinflector::new().with_normalization(true);
+    /// assert!(deinflector.deinflect("ﾀﾍﾞﾀ").iter().any(|w| w == "たべる"));
+    /// ```
+    pub fn with_normalization(mut self, enabled: bool) -> Self {
+        self.normalize = enabled;
+        self
+    }
+
+    /// Returns `word` normalized if normalization is enabled
+    fn prepare<'w>(&self, word: &'w str) -> Cow<'w, str> {
+        if self.normalize {
+            Cow::Owned(normalize(word))
+        } else {
+            Cow::Borrowed(word)
+        }
+    }
+
+    /// Returns the spellings of `word` that are deinflected: `word` itself, or its normalized
+    /// spellings if normalization is enabled, together with the map from their offsets back
+    /// to `word`
+    fn spellings<'w>(&self, word: &'w str) -> (Vec<Cow<'w, str>>, Option<OffsetMap>) {
+        if self.normalize {
+            let (spellings, offsets) = normalized_spellings(word);
+            (
+                spellings.into_iter().map(Cow::Owned).collect(),
+                Some(offsets),
+            )
+        } else {
+            (vec![Cow::Borrowed(word)], None)
+        }
+    }
+
+    /// Translates the inflection span of a deinflection of one of the [`spellings`](Self::spellings)
+    /// of a word to the word itself
+    fn to_original<'a>(
+        offsets: Option<&OffsetMap>,
+        deinflection: Deinflection<'a>,
+    ) -> Deinflection<'a> {
+        match offsets {
+            Some(offsets) => offsets.to_original(deinflection),
+            None => deinflection,
+        }
+    }
+
+    /// Returns the runtime rules of the deinflector, `None` if it uses the built-in ones
+    pub fn rule_set(&self) -> Option<&RuleSet> {
+        match &self.rules {
+            Rules::Builtin { .. } => None,
+            Rules::Custom(rule_set) => Some(rule_set),
+        }
+    }
+
+    /// Like [`deinflect`](crate::deinflect), but with the rules of this deinflector
+    pub fn deinflect(&self, word: &str) -> Vec<String> {
+        self.deinflect_detailed(word)
+            .into_iter()
+            .map(|deinflection| deinflection.word)
+            .collect()
+    }
+
+    /// Like [`Deinflector::deinflect`], but writes the results into `out` instead of allocating
+    /// a new vector. The previous contents of `out` are replaced, but the allocations of its
+    /// strings are reused, as are the internal buffers of the deinflector.
+    /// Once the buffers have grown large enough, repeated calls with the same `out` don't
+    /// allocate at all.
+    ///
+    /// # Examples
+    /// ```
+    /// use jp_deinflector::Deinflector;
+    /// let mut deinflector = Deinflector::new();
+    /// let mut out = Vec::new();
+    /// for word in ["食べた", "待った"] {
+    ///     deinflector.deinflect_into(word, &mut out);
+    ///     assert_eq!(out, jp_deinflector::deinflect(word));
+    /// }
+    /// ```
+    pub fn deinflect_into(&mut self, word: &str, out: &mut Vec<String>) {
+        let mut len = 0;
+        for spelling in self.spellings(word).0 {
+            len = self.deinflect_spelling_into(&spelling, out, len);
+        }
+        out.truncate(len);
+    }
+
+    /// Deinflects a single spelling and writes the results into `out`, starting at index `len`.
+    /// Returns the number of words in `out` that are now in use.
+    fn deinflect_spelling_into(&mut self, word: &str, out: &mut Vec<String>, len: usize) -> usize {
+        let Buffers {
+            arena,
+            nodes,
+            current,
+            seen,
+        } = &mut self.buffers;
+        arena.clear();
+        nodes.clear();
+        seen.clear();
+
+        push_kata_to_hira(arena, word);
+        nodes.push(Node {
+            start: 0,
+            end: arena.len(),
+            types: 0,
+        });
+
+        let mut i = 0;
+        while i < nodes.len() {
+            let node = &nodes[i];
+            let types = node.types;
+            current.clear();
+            current.push_str(&arena[node.start..node.end]);
+            i += 1;
+            if !seen.insert(fxhash::hash(current.as_bytes())) {
+                continue;
+            }
+
+            for suffix in capped_suffixes(current, self.rules.max_suffix_length()) {
+                let stem = &current[..current.len() - suffix.len()];
+                self.rules.for_each_rule(suffix, |rule| {
+                    if types != 0 && type_mask(rule.rules_in) & types == 0 {
+                        return;
+                    }
+                    let Some(stem) = strip_honorific_prefix(&rule, stem) else {
+                        return;
+                    };
+                    if stem.is_empty() && rule.kana_out.is_empty() {
+                        return;
+                    }
+                    let start = arena.len();
+                    arena.push_str(stem);
+                    arena.push_str(rule.kana_out);
+                    nodes.push(Node {
+                        start,
+                        end: arena.len(),
+                        types: type_mask(rule.rules_out),
+                    });
+                });
+            }
+        }
+
+        // The first node is the input itself
+        let candidates = &nodes[1..];
+        for (j, node) in candidates.iter().enumerate() {
+            let candidate = &arena[node.start..node.end];
+            match out.get_mut(len + j) {
+                Some(word) => {
+                    word.clear();
+                    word.push_str(candidate);
+                }
+                None => out.push(candidate.to_string()),
+            }
+        }
+        len + candidates.len()
+    }
+
+    /// Like [`deinflect_detailed`](crate::deinflect_detailed), but with the rules of this
+    /// deinflector
+    pub fn deinflect_detailed(&self, word: &str) -> Vec<Deinflection<'_>> {
+        let (spellings, offsets) = self.spellings(word);
+        spellings
+            .iter()
+            .flat_map(|spelling| deinflect_with_rules(&self.rules, spelling))
+            .map(|deinflection| Self::to_original(offsets.as_ref(), deinflection))
+            .collect()
+    }
+
+    /// Like [`deinflect_iter`](crate::deinflect_iter), but with the rules of this deinflector
+    pub fn deinflect_iter(&self, word: &str) -> impl Iterator<Item = Deinflection<'_>> {
+        let (spellings, offsets) = self.spellings(word);
+        let spellings: Vec<String> = spellings.into_iter().map(Cow::into_owned).collect();
+        spellings
+            .into_iter()
+            .flat_map(|spelling| DeinflectionIter::new(&self.rules, &spelling))
+            .map(move |deinflection| Self::to_original(offsets.as_ref(), deinflection))
+    }
+
+    /// Like [`deinflect_with_lexicon`](crate::deinflect_with_lexicon), but with the rules of
+    /// this deinflector
+    pub fn deinflect_with_lexicon(
+        &self,
+        word: &str,
+        lexicon: &impl Lexicon,
+    ) -> Vec<Deinflection<'_>> {
+        lexicon::filter(self.deinflect_detailed(word), lexicon)
+    }
+
+    /// Like [`scan_with`](crate::scan_with), but with the rules of this deinflector
+    pub fn scan_with(
+        &self,
+        text: &str,
+        start: usize,
+        is_known: impl FnMut(&Deinflection) -> bool,
+    ) -> Option<ScanMatch<'_>> {
+        scan::scan_with_rules(&self.rules, self.normalize, text, start, is_known)
+    }
+
+    /// Like [`deinflect_ranked`](crate::deinflect_ranked), but with the rules of this deinflector
+    pub fn deinflect_ranked(&self, word: &str) -> Vec<RankedDeinflection<'_>> {
+        rank::rank(&self.prepare(word), self.deinflect_detailed(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deinflect::deinflect;
+
+    #[test]
+    fn test_deinflect_into_matches_deinflect() {
+        let mut deinflector = Deinflector::new();
+        let mut out = Vec::new();
+        for word in [
+            "食べさせられなかった",
+            "静かではありませんでした",
+            "ヨマナイ",
+            "行っちゃった",
+            "た",
+            "",
+        ] {
+            deinflector.deinflect_into(word, &mut out);
+            assert_eq!(out, deinflect(word), "Results differ for '{}'", word);
+        }
+    }
+
+    #[test]
+    fn test_deinflect_into_replaces_previous_results() {
+        let mut deinflector = Deinflector::new();
+        let mut out = vec!["前の結果".to_string(); 1000];
+        deinflector.deinflect_into("待った", &mut out);
+        assert_eq!(out, deinflect("待った"));
+
+        deinflector.deinflect_into("食べさせられなかった", &mut out);
+        deinflector.deinflect_into("待った", &mut out);
+        assert_eq!(out, deinflect("待った"));
+    }
+
+    #[test]
+    fn test_deinflect_into_custom_rules() {
+        let mut deinflector = Deinflector::with_rules(RuleSet::builtin());
+        let mut out = Vec::new();
+        deinflector.deinflect_into("食べさせられなかった", &mut out);
+        let mut expected = deinflect("食べさせられなかった");
+        out.sort();
+        expected.sort();
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_without_colloquial() {
+        let formal = Deinflector::new().with_colloquial(false);
+        let custom = Deinflector::with_rules(RuleSet::builtin()).with_colloquial(false);
+        for deinflector in [&formal, &custom] {
+            assert!(!deinflector
+                .deinflect("食べてる")
+                .contains(&"食べる".to_string()));
+            assert!(!deinflector
+                .deinflect("行っちゃった")
+                .contains(&"行く".to_string()));
+            assert!(deinflector
+                .deinflect("食べている")
+                .contains(&"食べる".to_string()));
+            // The potential of 食べつ shares the suffix てる with the contracted progressive
+            assert!(deinflector
+                .deinflect("食べてる")
+                .contains(&"食べつ".to_string()));
+        }
+
+        let mut expected = formal.deinflect("食べさせられなかった");
+        let mut actual = custom.deinflect("食べさせられなかった");
+        expected.sort();
+        actual.sort();
+        assert_eq!(actual, expected);
+
+        // Enabling them again
+        let colloquial = formal.with_colloquial(true);
+        assert!(colloquial
+            .deinflect("食べてる")
+            .contains(&"食べる".to_string()));
+        assert!(colloquial.rule_set().is_none());
+
+        let mut rules = RuleSet::builtin();
+        rules.remove_colloquial();
+        let colloquial = Deinflector::with_rules(rules).with_colloquial(true);
+        assert_eq!(
+            colloquial.rule_set().unwrap().len(),
+            RuleSet::builtin().len()
+        );
+        for word in ["食べてる", "行っちゃった", "行かなきゃ"] {
+            let mut expected = Deinflector::new().deinflect(word);
+            let mut actual = colloquial.deinflect(word);
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected, "Results differ for '{}'", word);
+        }
+        // Rules that the rule set already contains are not added twice
+        let colloquial = colloquial.with_colloquial(true);
+        assert_eq!(
+            colloquial.rule_set().unwrap().len(),
+            RuleSet::builtin().len()
+        );
+    }
+
+    #[test]
+    fn test_with_classical() {
+        let builtin = Deinflector::new().with_classical(true);
+        let custom = Deinflector::with_rules(RuleSet::builtin()).with_classical(true);
+        for word in ["知らざりき", "受くれば", "行くべき", "食べさせられなかった"]
+        {
+            let mut expected = builtin.deinflect(word);
+            let mut actual = custom.deinflect(word);
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected, "Results differ for '{}'", word);
+        }
+
+        let custom = custom.with_classical(false);
+        assert_eq!(custom.rule_set().unwrap().len(), RuleSet::builtin().len());
+        assert!(!custom.deinflect("起くる").contains(&"起く".to_string()));
+    }
+
+    #[test]
+    fn test_normalization() {
+        let deinflector = Deinflector::new().with_normalization(true);
+        let mut out = Vec::new();
+        for word in ["ﾀﾍﾞﾀ", "ｶｯｺｲｲ", "すゞしかった"] {
+            let words = deinflector.deinflect(word);
+            assert_eq!(words, deinflect(&normalize(word)));
+            deinflector.clone().deinflect_into(word, &mut out);
+            assert_eq!(out, words);
+        }
+        assert!(deinflector
+            .deinflect("すゞしかった")
+            .contains(&"すずしい".to_string()));
+        for (word, expected) in [
+            ("行こー", "行く"),
+            ("食べよー", "食べる"),
+            ("でしょー", "だ"),
+            ("すげー", "すごい"),
+        ] {
+            assert!(deinflector.deinflect(word).iter().any(|w| w == expected));
+            deinflector.clone().deinflect_into(word, &mut out);
+            assert_eq!(out, deinflector.deinflect(word));
+            assert_eq!(
+                deinflector
+                    .deinflect_iter(word)
+                    .map(|d| d.word)
+                    .collect::<Vec<_>>(),
+                out
+            );
+        }
+        assert!(!Deinflector::new()
+            .deinflect("ﾀﾍﾞﾀ")
+            .contains(&"たべる".to_string()));
+
+        let text = "ｶﾞｯｺｳﾆｲｯﾀ";
+        let found = deinflector
+            .scan_with(text, text.find('ｲ').unwrap(), |d| d.word == "いく")
+            .unwrap();
+        // The span refers to the original text
+        assert_eq!(&text[found.span], "ｲｯﾀ");
+    }
+
+    #[test]
+    fn test_normalized_inflection_spans() {
+        let deinflector = Deinflector::new().with_normalization(true);
+        for (input, word, inflected, original) in [
+            ("ﾀﾍﾞﾀ", "たべる", "ﾀ", "ﾀﾍﾞル"),
+            ("ｲｺｰ", "いく", "ｺｰ", "ｲク"),
+            ("行こー", "行く", "こー", "行く"),
+            ("すげー", "すごい", "げー", "すごい"),
+        ] {
+            let deinflection = deinflector
+                .deinflect_detailed(input)
+                .into_iter()
+                .find(|d| d.word == word)
+                .unwrap();
+            assert_eq!(&input[deinflection.inflection_span.clone()], inflected);
+            assert_eq!(deinflection.in_original_script(input), original);
+
+            let lazy = deinflector
+                .deinflect_iter(input)
+                .find(|d| d.word == word)
+                .unwrap();
+            assert_eq!(lazy.inflection_span, deinflection.inflection_span);
+
+            let found = deinflector.scan_with(input, 0, |d| d.word == word).unwrap();
+            assert_eq!(found.span, 0..input.len());
+            assert!(found
+                .deinflections
+                .iter()
+                .any(|d| d.inflection_span == deinflection.inflection_span));
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! C API, see `include/jp_deinflector.h`.
+//!
+//! All strings are UTF-8 and null-terminated. Strings returned by this API must be freed
+//! with [`jp_string_free`], result lists with [`jp_deinflections_free`]. Functions that return a
+//! pointer return null if an argument is null, not valid UTF-8 or out of range.
+
+use crate::deinflect::{deinflect_detailed, Deinflection};
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+/// The deinflections of a word, see [`jp_deinflect`]
+pub struct JpDeinflections {
+    deinflections: Vec<Deinflection<'static>>,
+}
+
+unsafe fn to_str<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+    CStr::from_ptr(s).to_str().ok()
+}
+
+fn into_c_string(s: &str) -> *mut c_char {
+    // The strings of this crate never contain null characters unless the input did
+    CString::new(s).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+unsafe fn get<'a>(list: *const JpDeinflections, index: usize) -> Option<&'a Deinflection<'static>> {
+    list.as_ref()?.deinflections.get(index)
+}
+
+/// Deinflects `word`, see [`deinflect_detailed`]
+///
+/// # Safety
+/// `word` must be null or a null-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn jp_deinflect(word: *const c_char) -> *mut JpDeinflections {
+    match to_str(word) {
+        Some(word) => Box::into_raw(Box::new(JpDeinflections {
+            deinflections: deinflect_detailed(word),
+        })),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Frees a result list returned by [`jp_deinflect`]
+///
+/// # Safety
+/// `list` must be null or a list returned by [`jp_deinflect`] that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn jp_deinflections_free(list: *mut JpDeinflections) {
+    if !list.is_null() {
+        drop(Box::from_raw(list));
+    }
+}
+
+/// Returns the number of candidates in `list`
+///
+/// # Safety
+/// `list` must be null or a list returned by [`jp_deinflect`] that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn jp_deinflections_len(list: *const JpDeinflections) -> usize {
+    list.as_ref().map_or(0, |list| list.deinflections.len())
+}
+
+/// Returns the deinflected word of the candidate at `index`
+///
+/// # Safety
+/// `list` must be null or a list returned by [`jp_deinflect`] that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn jp_deinflections_word(
+    list: *const JpDeinflections,
+    index: usize,
+) -> *mut c_char {
+    get(list, index).map_or(ptr::null_mut(), |d| into_c_string(&d.word))
+}
+
+/// Returns the number of rule types of the candidate at `index`
+///
+/// # Safety
+/// `list` must be null or a list returned by [`jp_deinflect`] that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn jp_deinflections_type_count(
+    list: *const JpDeinflections,
+    index: usize,
+) -> usize {
+    get(list, index).map_or(0, |d| d.types.len())
+}
+
+/// Returns the name of a rule type of the candidate at `index`, e.g. "V5T"
+///
+/// # Safety
+/// `list` must be null or a list returned by [`jp_deinflect`] that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn jp_deinflections_type(
+    list: *const JpDeinflections,
+    index: usize,
+    type_index: usize,
+) -> *mut c_char {
+    get(list, index)
+        .and_then(|d| d.types.get(type_index))
+        .map_or(ptr::null_mut(), |t| into_c_string(&format!("{:?}", t)))
+}
+
+/// Returns the number of inflections that lead to the candidate at `index`
+///
+/// # Safety
+/// `list` must be null or a list returned by [`jp_deinflect`] that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn jp_deinflections_inflection_count(
+    list: *const JpDeinflections,
+    index: usize,
+) -> usize {
+    get(list, index).map_or(0, |d| d.rules.len())
+}
+
+/// Returns the English name of an inflection of the candidate at `index`, e.g. "past".
+/// The inflections are ordered from the base form outwards.
+///
+/// # Safety
+/// `list` must be null or a list returned by [`jp_deinflect`] that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn jp_deinflections_inflection(
+    list: *const JpDeinflections,
+    index: usize,
+    inflection_index: usize,
+) -> *mut c_char {
+    get(list, index)
+        .and_then(|d| d.rules.get(inflection_index))
+        .map_or(ptr::null_mut(), |rule| {
+            into_c_string(rule.kind.english_name())
+        })
+}
+
+/// Converts the katakana in `kata` to hiragana, see [`kata_to_hira`](crate::kata_to_hira)
+///
+/// # Safety
+/// `kata` must be null or a null-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn jp_kata_to_hira(kata: *const c_char) -> *mut c_char {
+    to_str(kata).map_or(ptr::null_mut(), |kata| {
+        into_c_string(&crate::kata_to_hira(kata))
+    })
+}
+
+/// Frees a string returned by this API
+///
+/// # Safety
+/// `s` must be null or a string returned by this API that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn jp_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Takes ownership of a string returned by the API
+    unsafe fn take(s: *mut c_char) -> Option<String> {
+        if s.is_null() {
+            return None;
+        }
+        let string = CStr::from_ptr(s).to_str().unwrap().to_string();
+        jp_string_free(s);
+        Some(string)
+    }
+
+    #[test]
+    fn test_deinflect() {
+        unsafe {
+            let list = jp_deinflect(c"待った".as_ptr());
+            assert!(!list.is_null());
+            let len = jp_deinflections_len(list);
+            assert_eq!(len, deinflect_detailed("待った").len());
+
+            let matsu = (0..len)
+                .find(|&i| take(jp_deinflections_word(list, i)).unwrap() == "待つ")
+                .unwrap();
+            assert_eq!(jp_deinflections_type_count(list, matsu), 1);
+            assert_eq!(take(jp_deinflections_type(list, matsu, 0)).unwrap(), "V5T");
+            assert_eq!(jp_deinflections_inflection_count(list, matsu), 1);
+            assert_eq!(
+                take(jp_deinflections_inflection(list, matsu, 0)).unwrap(),
+                "past"
+            );
+
+            // Out of range
+            assert!(jp_deinflections_word(list, len).is_null());
+            assert!(jp_deinflections_type(list, matsu, 1).is_null());
+            assert_eq!(jp_deinflections_type_count(list, len), 0);
+            jp_deinflections_free(list);
+        }
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        unsafe {
+            assert!(jp_deinflect(ptr::null()).is_null());
+            // Not UTF-8
+            assert!(jp_deinflect(c"\xff".as_ptr()).is_null());
+            assert_eq!(jp_deinflections_len(ptr::null()), 0);
+            assert!(jp_deinflections_word(ptr::null(), 0).is_null());
+            jp_deinflections_free(ptr::null_mut());
+            jp_string_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_kata_to_hira() {
+        unsafe {
+            assert_eq!(take(jp_kata_to_hira(c"マッタ".as_ptr())).unwrap(), "まった");
+            assert!(jp_kata_to_hira(ptr::null()).is_null());
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use crate::deinflect::{concatenate, is_honorific_stem, DeinflectionRule, RuleType};
+use crate::deinflection_rules::{all_rules, MAX_SUFFIX_LENGTH};
+use crate::inflection_kind::InflectionKind;
+use fxhash::{FxHashMap, FxHashSet};
+use std::sync::OnceLock;
+
+/// The maximum number of inflections that [`inflect`] chains together,
+/// e.g. 書かせられなかった (causative -> passive -> negative -> past) needs 4
+pub const DEFAULT_MAX_INFLECTION_DEPTH: usize = 4;
+
+/// An inflected form of a word together with the rules that produce it
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Inflection {
+    /// The inflected form
+    pub word: String,
+    /// The types of the inflected form, which determine how it can be inflected further.
+    /// Empty if it can't be inflected any further.
+    pub types: &'static [RuleType],
+    /// The rules whose inflections were applied, ordered from the base form outwards
+    pub rules: Vec<DeinflectionRule<'static>>,
+}
+
+impl Inflection {
+    /// Returns the kinds of the applied inflections, ordered like [`Inflection::rules`]
+    pub fn inflections(&self) -> impl Iterator<Item = InflectionKind> + '_ {
+        self.rules.iter().map(|rule| rule.kind)
+    }
+}
+
+struct InflectedWord {
+    word: String,
+    types: &'static [RuleType],
+    rule: DeinflectionRule<'static>,
+    /// Index of the word this one was inflected from, `None` if it was inflected from the base form
+    parent: Option<usize>,
+    depth: usize,
+}
+
+/// Applies the rule backwards, i.e. replaces its `kana_out` at the end of `word` by `kana_in`,
+/// if `word` has one of the types the rule deinflects to.
+/// Honorific and humble forms also get their お or ご prefix.
+fn apply_inverse(
+    rule: &DeinflectionRule,
+    kana_in: &str,
+    word: &str,
+    types: &[RuleType],
+) -> Option<String> {
+    if !rule.rules_out.iter().any(|r| types.contains(r)) {
+        return None;
+    }
+    let stem = word.strip_suffix(rule.kana_out)?;
+    if rule.kind.has_honorific_prefix() {
+        // Otherwise the prefix couldn't be stripped again, e.g. for ご飯にする
+        if !is_honorific_stem(rule.rules_out, stem) {
+            return None;
+        }
+        // Sino-Japanese nouns take ご, e.g. ご説明になる, everything else お
+        let prefix = if types.contains(&RuleType::Vs) {
+            "ご"
+        } else {
+            "お"
+        };
+        return Some(concatenate(&concatenate(prefix, stem), kana_in));
+    }
+    Some(concatenate(stem, kana_in))
+}
+
+/// The rules that [`inflect`] applies backwards, indexed by the types they deinflect to
+struct InverseIndex {
+    /// All rules together with their `kana_in`, in the order of the rule tables
+    rules: Vec<(&'static str, &'static DeinflectionRule<'static>)>,
+    /// The indices into `rules` of the rules with the type in their `rules_out`
+    by_type: FxHashMap<RuleType, Vec<usize>>,
+}
+
+impl InverseIndex {
+    fn new() -> Self {
+        let rules: Vec<_> = all_rules()
+            // Suffixes this long are never looked up, so these forms couldn't be deinflected
+            .filter(|(kana_in, _)| kana_in.chars().count() <= MAX_SUFFIX_LENGTH)
+            .flat_map(|(kana_in, rules)| rules.iter().map(move |rule| (*kana_in, rule)))
+            .collect();
+        let mut by_type: FxHashMap<RuleType, Vec<usize>> = FxHashMap::default();
+        for (i, (_, rule)) in rules.iter().enumerate() {
+            for rule_type in rule.rules_out {
+                by_type.entry(*rule_type).or_default().push(i);
+            }
+        }
+        Self { rules, by_type }
+    }
+}
+
+static INVERSE_INDEX: OnceLock<InverseIndex> = OnceLock::new();
+
+/// Calls `f` with every word that a single inflection of `word` produces
+fn inflect_one_iteration(
+    word: &str,
+    types: &[RuleType],
+    mut f: impl FnMut(String, DeinflectionRule<'static>),
+) {
+    let index = INVERSE_INDEX.get_or_init(InverseIndex::new);
+    let mut indices: Vec<usize> = types
+        .iter()
+        .filter_map(|rule_type| index.by_type.get(rule_type))
+        .flatten()
+        .copied()
+        .collect();
+    // Rules for several of the types are only applied once, in the order of the tables
+    if types.len() > 1 {
+        indices.sort_unstable();
+        indices.dedup();
+    }
+    for i in indices {
+        let (kana_in, rule) = index.rules[i];
+        if let Some(inflected) = apply_inverse(rule, kana_in, word, types) {
+            f(inflected, *rule);
+        }
+    }
+}
+
+/// Follows the parent links of the inflection at `index` back to the base form
+fn collect_rules(inflections: &[InflectedWord], index: usize) -> Vec<DeinflectionRule<'static>> {
+    let mut rules = Vec::new();
+    let mut current = Some(index);
+    while let Some(i) = current {
+        rules.push(inflections[i].rule);
+        current = inflections[i].parent;
+    }
+    rules.reverse();
+    rules
+}
+
+/// Like [`inflect`], but chains at most `max_depth` inflections together
+pub fn inflect_with_max_depth(
+    word: &str,
+    rule_type: RuleType,
+    max_depth: usize,
+) -> Vec<Inflection> {
+    let mut seen: FxHashSet<usize> = FxHashSet::default();
+    seen.insert(fxhash::hash(word.as_bytes()));
+
+    let mut inflections = Vec::new();
+    if max_depth > 0 {
+        inflect_one_iteration(word, &[rule_type], |inflected, rule| {
+            if seen.insert(fxhash::hash(inflected.as_bytes())) {
+                inflections.push(InflectedWord {
+                    word: inflected,
+                    types: rule.rules_in,
+                    rule,
+                    parent: None,
+                    depth: 1,
+                });
+            }
+        });
+    }
+
+    let mut i = 0;
+    while i < inflections.len() {
+        let current = &inflections[i];
+        if current.depth < max_depth {
+            let depth = current.depth + 1;
+            let mut new_inflections = Vec::new();
+            inflect_one_iteration(&current.word, current.types, |inflected, rule| {
+                if seen.insert(fxhash::hash(inflected.as_bytes())) {
+                    new_inflections.push(InflectedWord {
+                        word: inflected,
+                        types: rule.rules_in,
+                        rule,
+                        parent: Some(i),
+                        depth,
+                    });
+                }
+            });
+            inflections.extend(new_inflections);
+        }
+        i += 1;
+    }
+
+    let rules: Vec<_> = (0..inflections.len())
+        .map(|i| collect_rules(&inflections, i))
+        .collect();
+
+    inflections
+        .into_iter()
+        .zip(rules)
+        .map(|(inflection, rules)| Inflection {
+            word: inflection.word,
+            types: inflection.types,
+            rules,
+        })
+        .collect()
+}
+
+/// Returns the inflected forms of the base form `word` of type `rule_type` that the deinflection
+/// rules can undo, i.e. the inverse of [`deinflect`](crate::deinflect).
+/// Every form is only returned once, together with the shortest chain of inflections producing it.
+/// At most [`DEFAULT_MAX_INFLECTION_DEPTH`] inflections are chained together.
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::{inflect, RuleType};
+/// let inflections = inflect("書く", RuleType::V5K);
+/// for form in ["書かない", "書いた", "書ける", "書かせられなかった"] {
+///     assert!(inflections.iter().any(|i| i.word == form));
+/// }
+/// ```
+pub fn inflect(word: &str, rule_type: RuleType) -> Vec<Inflection> {
+    inflect_with_max_depth(word, rule_type, DEFAULT_MAX_INFLECTION_DEPTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'a>(inflections: &'a [Inflection], word: &str) -> Option<&'a Inflection> {
+        inflections.iter().find(|i| i.word == word)
+    }
+
+    #[test]
+    fn test_inflects_godan() {
+        let inflections = inflect("書く", RuleType::V5K);
+        for form in [
+            "書かない",
+            "書いた",
+            "書いて",
+            "書ける",
+            "書かれる",
+            "書かせる",
+            "書きます",
+            "書きませんでした",
+            "書こう",
+            "書け",
+            "書けば",
+            "書かなかった",
+            "書いている",
+        ] {
+            assert!(
+                find(&inflections, form).is_some(),
+                "Missing inflection '{}'",
+                form
+            );
+        }
+    }
+
+    #[test]
+    fn test_inflection_chain() {
+        let inflections = inflect("書く", RuleType::V5K);
+        let inflection = find(&inflections, "書かせられなかった").unwrap();
+        assert_eq!(
+            inflection.inflections().collect::<Vec<_>>(),
+            vec![
+                InflectionKind::Causative,
+                InflectionKind::PotentialOrPassive,
+                InflectionKind::Negative,
+                InflectionKind::Past
+            ]
+        );
+        assert!(inflection.types.is_empty());
+    }
+
+    #[test]
+    fn test_respects_types() {
+        let inflections = inflect("食べる", RuleType::V1);
+        assert!(find(&inflections, "食べない").is_some());
+        assert!(find(&inflections, "食べさせる").is_some());
+        // Godan inflections must not be applied to ichidan verbs
+        assert!(find(&inflections, "食べらない").is_none());
+        assert!(find(&inflections, "食べった").is_none());
+
+        let inflections = inflect("高い", RuleType::AdjI);
+        assert!(find(&inflections, "高かった").is_some());
+        assert!(find(&inflections, "高くない").is_some());
+        assert!(find(&inflections, "高さ").is_some());
+        assert!(find(&inflections, "高いない").is_none());
+
+        let inflections = inflect("静か", RuleType::AdjNa);
+        assert!(find(&inflections, "静かだった").is_some());
+        assert!(find(&inflections, "静かじゃなかった").is_some());
+        assert!(find(&inflections, "静かではありませんでした").is_some());
+        assert!(find(&inflections, "静かかった").is_none());
+    }
+
+    #[test]
+    fn test_max_depth() {
+        assert!(inflect_with_max_depth("書く", RuleType::V5K, 0).is_empty());
+
+        let inflections = inflect_with_max_depth("書く", RuleType::V5K, 1);
+        assert!(find(&inflections, "書かない").is_some());
+        assert!(find(&inflections, "書かなかった").is_none());
+        assert!(inflections.iter().all(|i| i.rules.len() == 1));
+    }
+
+    #[test]
+    fn test_irregular_godan() {
+        let inflections = inflect("書く", RuleType::V5K);
+        assert!(find(&inflections, "書いちゃう").is_some());
+        assert!(find(&inflections, "書っちゃう").is_none());
+
+        let inflections = inflect("行く", RuleType::V5KS);
+        assert!(find(&inflections, "行った").is_some());
+        assert!(find(&inflections, "行って").is_some());
+        assert!(find(&inflections, "行っちゃう").is_some());
+        assert!(find(&inflections, "行いた").is_none());
+
+        let inflections = inflect("ある", RuleType::V5RI);
+        assert!(find(&inflections, "あった").is_some());
+        assert!(find(&inflections, "あります").is_some());
+        assert!(find(&inflections, "あらない").is_none());
+
+        let inflections = inflect("くださる", RuleType::V5Aru);
+        assert!(find(&inflections, "ください").is_some());
+        assert!(find(&inflections, "くださいます").is_some());
+        assert!(find(&inflections, "くださった").is_some());
+        assert!(find(&inflections, "くださります").is_none());
+
+        let inflections = inflect("問う", RuleType::V5US);
+        assert!(find(&inflections, "問うた").is_some());
+        assert!(find(&inflections, "問った").is_none());
+    }
+
+    #[test]
+    fn test_honorific_prefix() {
+        let inflections = inflect("読む", RuleType::V5M);
+        assert!(find(&inflections, "お読みになる").is_some());
+        assert!(find(&inflections, "お読みする").is_some());
+        assert!(find(&inflections, "読みになる").is_none());
+
+        let inflections = inflect("説明する", RuleType::Vs);
+        assert!(find(&inflections, "ご説明になる").is_some());
+        assert!(find(&inflections, "ご説明いたします").is_some());
+    }
+
+    #[test]
+    fn test_forms_are_unique() {
+        let inflections = inflect("食べる", RuleType::V1);
+        let unique: FxHashSet<&str> = inflections.iter().map(|i| i.word.as_str()).collect();
+        assert_eq!(unique.len(), inflections.len());
+    }
+}
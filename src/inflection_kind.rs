@@ -0,0 +1,373 @@
+use std::fmt;
+
+/// The kind of inflection a deinflection rule undoes, e.g. "past" for 食べた -> 食べる.
+/// The English names follow the reason names used by Yomichan/Yomitan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InflectionKind {
+    /// 読めば
+    Ba,
+    /// 高けりゃ, 読まなきゃ
+    Kya,
+    /// 読んじゃう
+    Chau,
+    /// 読んじまう
+    Chimau,
+    /// 読んじゃ
+    Cha,
+    /// 読んでしまう
+    Shimau,
+    /// 読みなさい
+    Nasai,
+    /// 読みそう
+    Sou,
+    /// 読みすぎる
+    Sugiru,
+    /// 読みたい
+    Tai,
+    /// 読んだら
+    Tara,
+    /// 読んだり
+    Tari,
+    /// 読んで
+    Te,
+    /// 読まず
+    Zu,
+    /// 読まぬ
+    Nu,
+    /// 早く
+    Adverbial,
+    /// 読ませる
+    Causative,
+    /// 読まされる
+    CausativePassive,
+    /// 読め
+    Imperative,
+    /// 読むな
+    ImperativeNegative,
+    /// 読み
+    MasuStem,
+    /// 読まない
+    Negative,
+    /// 早さ
+    Sa,
+    /// 重み
+    Mi,
+    /// 寒がる
+    Garu,
+    /// 読まれる
+    Passive,
+    /// 食べられる
+    PotentialOrPassive,
+    /// 読んだ
+    Past,
+    /// 読みます
+    Polite,
+    /// 読みません
+    PoliteNegative,
+    /// 読みました
+    PolitePast,
+    /// 読みませんでした
+    PolitePastNegative,
+    /// 読みましょう
+    PoliteVolitional,
+    /// 読める
+    Potential,
+    /// 読もう
+    Volitional,
+    /// 読みながら
+    Nagara,
+    /// 知らん
+    NSlang,
+    /// 読んどく
+    Toku,
+    /// 読んでいる
+    Progressive,
+    /// 食べないでいる
+    NaideIru,
+    /// 楽しげ
+    Ge,
+    /// 早き
+    Ki,
+    /// すげえ
+    Ee,
+    /// 静かだ
+    Copula,
+    /// 静かな
+    Attributive,
+    /// 静かなら
+    Nara,
+    /// 静かである
+    Dearu,
+    /// 読みなさる
+    Nasaru,
+    /// 教えてくださる
+    Kudasaru,
+    /// 読んでいらっしゃる
+    Irassharu,
+    /// 読ませていただく
+    Itadaku,
+    /// お読みになる, ご説明になる
+    Honorific,
+    /// お読みする, ご説明いたす
+    Humble,
+    /// 知らざる (classical)
+    Zaru,
+    /// 行かむ (classical)
+    Mu,
+    /// 行きき, 行きし (classical)
+    ClassicalPast,
+    /// 行きけり (classical)
+    Keri,
+    /// 行きたり, 行けり (classical)
+    Perfective,
+    /// 受くれ, 高けれ (classical)
+    Realis,
+    /// 行くべし (classical)
+    Beshi,
+    /// 静かなり, 行くなり (classical)
+    Nari,
+}
+
+impl InflectionKind {
+    /// All inflection kinds in declaration order
+    pub const ALL: &'static [InflectionKind] = &[
+        InflectionKind::Ba,
+        InflectionKind::Kya,
+        InflectionKind::Chau,
+        InflectionKind::Chimau,
+        InflectionKind::Cha,
+        InflectionKind::Shimau,
+        InflectionKind::Nasai,
+        InflectionKind::Sou,
+        InflectionKind::Sugiru,
+        InflectionKind::Tai,
+        InflectionKind::Tara,
+        InflectionKind::Tari,
+        InflectionKind::Te,
+        InflectionKind::Zu,
+        InflectionKind::Nu,
+        InflectionKind::Adverbial,
+        InflectionKind::Causative,
+        InflectionKind::CausativePassive,
+        InflectionKind::Imperative,
+        InflectionKind::ImperativeNegative,
+        InflectionKind::MasuStem,
+        InflectionKind::Negative,
+        InflectionKind::Sa,
+        InflectionKind::Mi,
+        InflectionKind::Garu,
+        InflectionKind::Passive,
+        InflectionKind::PotentialOrPassive,
+        InflectionKind::Past,
+        InflectionKind::Polite,
+        InflectionKind::PoliteNegative,
+        InflectionKind::PolitePast,
+        InflectionKind::PolitePastNegative,
+        InflectionKind::PoliteVolitional,
+        InflectionKind::Potential,
+        InflectionKind::Volitional,
+        InflectionKind::Nagara,
+        InflectionKind::NSlang,
+        InflectionKind::Toku,
+        InflectionKind::Progressive,
+        InflectionKind::NaideIru,
+        InflectionKind::Ge,
+        InflectionKind::Ki,
+        InflectionKind::Ee,
+        InflectionKind::Copula,
+        InflectionKind::Attributive,
+        InflectionKind::Nara,
+        InflectionKind::Dearu,
+        InflectionKind::Nasaru,
+        InflectionKind::Kudasaru,
+        InflectionKind::Irassharu,
+        InflectionKind::Itadaku,
+        InflectionKind::Honorific,
+        InflectionKind::Humble,
+        InflectionKind::Zaru,
+        InflectionKind::Mu,
+        InflectionKind::ClassicalPast,
+        InflectionKind::Keri,
+        InflectionKind::Perfective,
+        InflectionKind::Realis,
+        InflectionKind::Beshi,
+        InflectionKind::Nari,
+    ];
+
+    /// The English name of the inflection, e.g. "past" or "-te"
+    pub fn english_name(self) -> &'static str {
+        match self {
+            InflectionKind::Ba => "-ba",
+            InflectionKind::Kya => "-kya",
+            InflectionKind::Chau => "-chau",
+            InflectionKind::Chimau => "-chimau",
+            InflectionKind::Cha => "-cha",
+            InflectionKind::Shimau => "-shimau",
+            InflectionKind::Nasai => "-nasai",
+            InflectionKind::Sou => "-sou",
+            InflectionKind::Sugiru => "-sugiru",
+            InflectionKind::Tai => "-tai",
+            InflectionKind::Tara => "-tara",
+            InflectionKind::Tari => "-tari",
+            InflectionKind::Te => "-te",
+            InflectionKind::Zu => "-zu",
+            InflectionKind::Nu => "-nu",
+            InflectionKind::Adverbial => "adv",
+            InflectionKind::Causative => "causative",
+            InflectionKind::CausativePassive => "causative passive",
+            InflectionKind::Imperative => "imperative",
+            InflectionKind::ImperativeNegative => "imperative negative",
+            InflectionKind::MasuStem => "masu stem",
+            InflectionKind::Negative => "negative",
+            InflectionKind::Sa => "-sa",
+            InflectionKind::Mi => "-mi",
+            InflectionKind::Garu => "-garu",
+            InflectionKind::Passive => "passive",
+            InflectionKind::PotentialOrPassive => "potential or passive",
+            InflectionKind::Past => "past",
+            InflectionKind::Polite => "polite",
+            InflectionKind::PoliteNegative => "polite negative",
+            InflectionKind::PolitePast => "polite past",
+            InflectionKind::PolitePastNegative => "polite past negative",
+            InflectionKind::PoliteVolitional => "polite volitional",
+            InflectionKind::Potential => "potential",
+            InflectionKind::Volitional => "volitional",
+            InflectionKind::Nagara => "-nagara",
+            InflectionKind::NSlang => "n-slang",
+            InflectionKind::Toku => "-toku",
+            InflectionKind::Progressive => "progressive or perfect",
+            InflectionKind::NaideIru => "-naide iru",
+            InflectionKind::Ge => "-ge",
+            InflectionKind::Ki => "-ki",
+            InflectionKind::Ee => "-ee",
+            InflectionKind::Copula => "copula",
+            InflectionKind::Attributive => "attributive",
+            InflectionKind::Nara => "-nara",
+            InflectionKind::Dearu => "-de aru",
+            InflectionKind::Nasaru => "-nasaru",
+            InflectionKind::Kudasaru => "-te kudasaru",
+            InflectionKind::Irassharu => "-te irassharu",
+            InflectionKind::Itadaku => "-te itadaku",
+            InflectionKind::Honorific => "honorific",
+            InflectionKind::Humble => "humble",
+            InflectionKind::Zaru => "-zaru",
+            InflectionKind::Mu => "-mu",
+            InflectionKind::ClassicalPast => "classical past",
+            InflectionKind::Keri => "-keri",
+            InflectionKind::Perfective => "perfective",
+            InflectionKind::Realis => "realis",
+            InflectionKind::Beshi => "-beshi",
+            InflectionKind::Nari => "-nari",
+        }
+    }
+
+    /// Returns the inflection kind with the given [English name](InflectionKind::english_name)
+    ///
+    /// # Examples
+    /// ```
+    /// use jp_deinflector::InflectionKind;
+    /// assert_eq!(InflectionKind::from_english_name("past"), Some(InflectionKind::Past));
+    /// assert_eq!(InflectionKind::from_english_name("pasta"), None);
+    /// ```
+    pub fn from_english_name(name: &str) -> Option<Self> {
+        InflectionKind::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.english_name() == name)
+    }
+
+    /// The Japanese name of the inflection, e.g. "過去形" or "～て"
+    pub fn japanese_name(self) -> &'static str {
+        match self {
+            InflectionKind::Ba => "～ば",
+            InflectionKind::Kya => "～きゃ",
+            InflectionKind::Chau => "～ちゃう",
+            InflectionKind::Chimau => "～ちまう",
+            InflectionKind::Cha => "～ちゃ",
+            InflectionKind::Shimau => "～てしまう",
+            InflectionKind::Nasai => "～なさい",
+            InflectionKind::Sou => "～そう",
+            InflectionKind::Sugiru => "～すぎる",
+            InflectionKind::Tai => "～たい",
+            InflectionKind::Tara => "～たら",
+            InflectionKind::Tari => "～たり",
+            InflectionKind::Te => "～て",
+            InflectionKind::Zu => "～ず",
+            InflectionKind::Nu => "～ぬ",
+            InflectionKind::Adverbial => "副詞形",
+            InflectionKind::Causative => "使役形",
+            InflectionKind::CausativePassive => "使役受身形",
+            InflectionKind::Imperative => "命令形",
+            InflectionKind::ImperativeNegative => "禁止形",
+            InflectionKind::MasuStem => "連用形",
+            InflectionKind::Negative => "否定形",
+            InflectionKind::Sa => "～さ",
+            InflectionKind::Mi => "～み",
+            InflectionKind::Garu => "～がる",
+            InflectionKind::Passive => "受身形",
+            InflectionKind::PotentialOrPassive => "受身形・可能形",
+            InflectionKind::Past => "過去形",
+            InflectionKind::Polite => "～ます",
+            InflectionKind::PoliteNegative => "～ません",
+            InflectionKind::PolitePast => "～ました",
+            InflectionKind::PolitePastNegative => "～ませんでした",
+            InflectionKind::PoliteVolitional => "～ましょう",
+            InflectionKind::Potential => "可能形",
+            InflectionKind::Volitional => "意向形",
+            InflectionKind::Nagara => "～ながら",
+            InflectionKind::NSlang => "～ん",
+            InflectionKind::Toku => "～とく",
+            InflectionKind::Progressive => "～ている",
+            InflectionKind::NaideIru => "～ないでいる",
+            InflectionKind::Ge => "～げ",
+            InflectionKind::Ki => "～き",
+            InflectionKind::Ee => "～えー",
+            InflectionKind::Copula => "～だ",
+            InflectionKind::Attributive => "連体形",
+            InflectionKind::Nara => "～なら",
+            InflectionKind::Dearu => "～である",
+            InflectionKind::Nasaru => "～なさる",
+            InflectionKind::Kudasaru => "～てくださる",
+            InflectionKind::Irassharu => "～ていらっしゃる",
+            InflectionKind::Itadaku => "～ていただく",
+            InflectionKind::Honorific => "お～になる",
+            InflectionKind::Humble => "お～する",
+            InflectionKind::Zaru => "～ざる",
+            InflectionKind::Mu => "～む",
+            InflectionKind::ClassicalPast => "～き（過去）",
+            InflectionKind::Keri => "～けり",
+            InflectionKind::Perfective => "～たり・～り",
+            InflectionKind::Realis => "已然形",
+            InflectionKind::Beshi => "～べし",
+            InflectionKind::Nari => "～なり",
+        }
+    }
+
+    /// Whether the inflection puts お or ご in front of the word, e.g. お読みになる
+    pub(crate) fn has_honorific_prefix(self) -> bool {
+        matches!(self, InflectionKind::Honorific | InflectionKind::Humble)
+    }
+}
+
+impl fmt::Display for InflectionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.english_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_are_unique() {
+        for (i, a) in InflectionKind::ALL.iter().enumerate() {
+            for b in &InflectionKind::ALL[i + 1..] {
+                assert_ne!(a, b);
+                assert_ne!(a.english_name(), b.english_name());
+                assert_ne!(a.japanese_name(), b.japanese_name());
+            }
+        }
+    }
+}
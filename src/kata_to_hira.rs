@@ -8,20 +8,301 @@ pub fn kata_to_hira(kata: &str) -> String {
 
     // Preallocate the result with the same capacity
     let mut result = String::with_capacity(kata.len());
+    push_kata_to_hira(&mut result, kata);
+    result
+}
+
+/// Like [`kata_to_hira`], but appends the converted string to `out`
+pub(crate) fn push_kata_to_hira(out: &mut String, kata: &str) {
     for c in kata.chars() {
         if is_katakana(c) {
-            result.push(katakana_char_to_hira(c));
+            out.push(katakana_char_to_hira(c));
         } else {
-            result.push(c);
+            out.push(c);
+        }
+    }
+}
+
+/// Converts all hiragana characters contained in the input string `hira`
+/// into their katakana counterparts. All other characters are kept as they are.
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::hira_to_kata;
+/// assert_eq!(hira_to_kata("たべた"), "タベタ");
+/// assert_eq!(hira_to_kata("食べた"), "食ベタ");
+/// ```
+pub fn hira_to_kata(hira: &str) -> String {
+    hira.chars()
+        .map(|c| {
+            if is_hiragana(c) {
+                char::from_u32(c as u32 + 0x60).unwrap_or(c)
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// Converts romaji in the input string `romaji` into hiragana.
+/// Hepburn (shi, tsu, ja), Kunrei-shiki (si, tu, zya) and Nihon-shiki (di, du, kwa) spellings
+/// are accepted and can be mixed. Doubled consonants become っ, n becomes ん unless it starts a
+/// syllable (use n' or nn to separate it, e.g. kan'i), and long vowels written with a macron or
+/// circumflex are expanded (ō -> おう). A hyphen after a vowel of a word that is entirely
+/// romaji becomes ー (ra-men -> らーめん). Characters that aren't romaji are kept as they are.
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::romaji_to_hira;
+/// assert_eq!(romaji_to_hira("tabesaserareta"), "たべさせられた");
+/// assert_eq!(romaji_to_hira("ittenakatta"), "いってなかった");
+/// assert_eq!(romaji_to_hira("ikō"), "いこう");
+/// ```
+pub fn romaji_to_hira(romaji: &str) -> String {
+    let mut text = String::with_capacity(romaji.len());
+    for c in romaji.chars().flat_map(char::to_lowercase) {
+        match c {
+            'ā' | 'â' => text.push_str("aa"),
+            'ī' | 'î' => text.push_str("ii"),
+            'ū' | 'û' => text.push_str("uu"),
+            'ē' | 'ê' => text.push_str("ee"),
+            'ō' | 'ô' => text.push_str("ou"),
+            _ => text.push(c),
+        }
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let starts_syllable = |i: usize| {
+        chars
+            .get(i)
+            .is_some_and(|&c| is_romaji_vowel(c) || c == 'y')
+    };
+
+    let mut result = String::with_capacity(text.len() * 3);
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if c == 'n' && !starts_syllable(i + 1) {
+            result.push('ん');
+            // n' and nn are explicit spellings of ん, unless the second n starts a syllable
+            i += match next {
+                Some('\'') => 2,
+                Some('n') if !starts_syllable(i + 2) => 2,
+                _ => 1,
+            };
+            continue;
+        }
+        // Traditional Hepburn writes ん before labial consonants as m, e.g. shimbun
+        if c == 'm' && matches!(next, Some('b' | 'm' | 'p')) {
+            result.push('ん');
+            i += 1;
+            continue;
+        }
+        if c.is_ascii_lowercase()
+            && !is_romaji_vowel(c)
+            && (next == Some(c) || (c, next) == ('t', Some('c')))
+        {
+            result.push('っ');
+            i += 1;
+            continue;
+        }
+
+        let found = (1..=4).rev().find_map(|len| {
+            let romaji: String = chars.get(i..i + len)?.iter().collect();
+            Some((romaji_syllable(&romaji)?, len))
+        });
+        match found {
+            Some((kana, len)) => {
+                result.push_str(kana);
+                i += len;
+            }
+            None => {
+                let is_long_vowel = c == '-'
+                    && i > 0
+                    && is_romaji_vowel(chars[i - 1])
+                    && result.chars().last().is_some_and(is_hiragana)
+                    && is_romaji_word(&chars, i);
+                result.push(if is_long_vowel { 'ー' } else { c });
+                i += 1;
+            }
         }
     }
     result
 }
 
+/// Returns true if the word around `chars[i]`, i.e. the surrounding letters, apostrophes and
+/// hyphens, is romaji through and through, unlike e.g. hi-tech
+fn is_romaji_word(chars: &[char], i: usize) -> bool {
+    let is_word_char = |c: &char| c.is_ascii_alphabetic() || matches!(c, '\'' | '-');
+    let start = chars[..i]
+        .iter()
+        .rposition(|c| !is_word_char(c))
+        .map_or(0, |j| j + 1);
+    let end = chars[i..]
+        .iter()
+        .position(|c| !is_word_char(c))
+        .map_or(chars.len(), |j| i + j);
+    let word: String = chars[start..end].iter().filter(|&&c| c != '-').collect();
+    !romaji_to_hira(&word)
+        .chars()
+        .any(|c| c.is_ascii_alphabetic())
+}
+
+fn is_romaji_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'i' | 'u' | 'e' | 'o')
+}
+
+/// Returns the hiragana of a single romaji syllable
+fn romaji_syllable(romaji: &str) -> Option<&'static str> {
+    let kana = match romaji {
+        "a" => "あ",
+        "i" => "い",
+        "u" => "う",
+        "e" => "え",
+        "o" => "お",
+        "ka" => "か",
+        "ki" => "き",
+        "ku" => "く",
+        "ke" => "け",
+        "ko" => "こ",
+        "kya" => "きゃ",
+        "kyu" => "きゅ",
+        "kyo" => "きょ",
+        "kwa" => "くゎ",
+        "ga" => "が",
+        "gi" => "ぎ",
+        "gu" => "ぐ",
+        "ge" => "げ",
+        "go" => "ご",
+        "gya" => "ぎゃ",
+        "gyu" => "ぎゅ",
+        "gyo" => "ぎょ",
+        "gwa" => "ぐゎ",
+        "sa" => "さ",
+        "si" | "shi" => "し",
+        "su" => "す",
+        "se" => "せ",
+        "so" => "そ",
+        "sya" | "sha" => "しゃ",
+        "syu" | "shu" => "しゅ",
+        "syo" | "sho" => "しょ",
+        "she" => "しぇ",
+        "za" => "ざ",
+        "zi" | "ji" => "じ",
+        "zu" => "ず",
+        "ze" => "ぜ",
+        "zo" => "ぞ",
+        "zya" | "ja" | "jya" => "じゃ",
+        "zyu" | "ju" | "jyu" => "じゅ",
+        "zyo" | "jo" | "jyo" => "じょ",
+        "je" => "じぇ",
+        "ta" => "た",
+        "ti" | "chi" => "ち",
+        "tu" | "tsu" => "つ",
+        "te" => "て",
+        "to" => "と",
+        "tya" | "cha" => "ちゃ",
+        "tyu" | "chu" => "ちゅ",
+        "tyo" | "cho" => "ちょ",
+        "che" => "ちぇ",
+        "da" => "だ",
+        "di" => "ぢ",
+        "du" => "づ",
+        "de" => "で",
+        "do" => "ど",
+        "dya" => "ぢゃ",
+        "dyu" => "ぢゅ",
+        "dyo" => "ぢょ",
+        "na" => "な",
+        "ni" => "に",
+        "nu" => "ぬ",
+        "ne" => "ね",
+        "no" => "の",
+        "nya" => "にゃ",
+        "nyu" => "にゅ",
+        "nyo" => "にょ",
+        "ha" => "は",
+        "hi" => "ひ",
+        "hu" | "fu" => "ふ",
+        "he" => "へ",
+        "ho" => "ほ",
+        "hya" => "ひゃ",
+        "hyu" => "ひゅ",
+        "hyo" => "ひょ",
+        "fa" => "ふぁ",
+        "fi" => "ふぃ",
+        "fe" => "ふぇ",
+        "fo" => "ふぉ",
+        "ba" => "ば",
+        "bi" => "び",
+        "bu" => "ぶ",
+        "be" => "べ",
+        "bo" => "ぼ",
+        "bya" => "びゃ",
+        "byu" => "びゅ",
+        "byo" => "びょ",
+        "pa" => "ぱ",
+        "pi" => "ぴ",
+        "pu" => "ぷ",
+        "pe" => "ぺ",
+        "po" => "ぽ",
+        "pya" => "ぴゃ",
+        "pyu" => "ぴゅ",
+        "pyo" => "ぴょ",
+        "ma" => "ま",
+        "mi" => "み",
+        "mu" => "む",
+        "me" => "め",
+        "mo" => "も",
+        "mya" => "みゃ",
+        "myu" => "みゅ",
+        "myo" => "みょ",
+        "ya" => "や",
+        "yu" => "ゆ",
+        "yo" => "よ",
+        "ra" => "ら",
+        "ri" => "り",
+        "ru" => "る",
+        "re" => "れ",
+        "ro" => "ろ",
+        "rya" => "りゃ",
+        "ryu" => "りゅ",
+        "ryo" => "りょ",
+        "wa" => "わ",
+        "wi" => "ゐ",
+        "we" => "ゑ",
+        "wo" => "を",
+        "va" => "ゔぁ",
+        "vi" => "ゔぃ",
+        "vu" => "ゔ",
+        "ve" => "ゔぇ",
+        "vo" => "ゔぉ",
+        // Small kana as typed with an IME
+        "xa" | "la" => "ぁ",
+        "xi" | "li" => "ぃ",
+        "xu" | "lu" => "ぅ",
+        "xe" | "le" => "ぇ",
+        "xo" | "lo" => "ぉ",
+        "xya" | "lya" => "ゃ",
+        "xyu" | "lyu" => "ゅ",
+        "xyo" | "lyo" => "ょ",
+        "xtu" | "ltu" | "xtsu" | "ltsu" => "っ",
+        "xwa" | "lwa" => "ゎ",
+        _ => return None,
+    };
+    Some(kana)
+}
+
+/// The hiragana counterparts of the katakana range of [`is_katakana`]
+pub(crate) fn is_hiragana(c: char) -> bool {
+    let cp = c as u32;
+    (0x3041..=0x3096).contains(&cp)
+}
+
 /// Officially kata is the range 30A0-30FF (https://www.unicode.org/charts/PDF/U30A0.pdf),
 /// but I am not sure if something like '゠' or 'ヿ' should be converted too
 /// (won't matter for deinflection anyway though)
-fn is_katakana(c: char) -> bool {
+pub(crate) fn is_katakana(c: char) -> bool {
     let cp = c as u32;
     (0x30A1..=0x30F6).contains(&cp)
 }
@@ -42,7 +323,77 @@ mod tests {
         assert_eq!(kata_to_hira(kata), hira);
     }
 
+    #[test]
+    fn test_hira_to_kata() {
+        let kata = "アイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワヲンガギグゲゴザジズゼゾダヂヅデドバビブベボパピプペポヴ";
+        let hira = "あいうえおかきくけこさしすせそたちつてとなにぬねのはひふへほまみむめもやゆよらりるれろわをんがぎぐげござじずぜぞだぢづでどばびぶべぼぱぴぷぺぽゔ";
+        assert_eq!(hira_to_kata(hira), kata);
+        assert_eq!(hira_to_kata("ー々漢字abc"), "ー々漢字abc");
+    }
+
+    #[test]
+    fn test_romaji_to_hira() {
+        let cases = [
+            // Hepburn
+            ("tabesaserareta", "たべさせられた"),
+            ("shinjitsu", "しんじつ"),
+            ("chotto", "ちょっと"),
+            ("matcha", "まっちゃ"),
+            ("fujisan", "ふじさん"),
+            ("jouzu", "じょうず"),
+            // Kunrei-shiki
+            ("sinzitu", "しんじつ"),
+            ("tyotto", "ちょっと"),
+            ("huzisan", "ふじさん"),
+            ("zyouzu", "じょうず"),
+            // Nihon-shiki
+            ("tudukeru", "つづける"),
+            ("hanadi", "はなぢ"),
+            ("kwaji", "くゎじ"),
+            // ん
+            ("konnichiwa", "こんにちわ"),
+            ("konnnichiwa", "こんにちわ"),
+            ("onna", "おんな"),
+            ("kan'i", "かんい"),
+            ("kani", "かに"),
+            ("hon'ya", "ほんや"),
+            ("honya", "ほにゃ"),
+            ("shimbun", "しんぶん"),
+            ("yomen", "よめん"),
+            // Long vowels
+            ("tōkyō", "とうきょう"),
+            ("okāsan", "おかあさん"),
+            ("Tôkyô", "とうきょう"),
+            ("ra-men", "らーめん"),
+            ("su-pa-", "すーぱー"),
+            // Hyphens that don't lengthen a vowel of a romaji word
+            ("2-3", "2-3"),
+            ("kan-i", "かん-い"),
+            // Mixed and unknown input
+            ("TABETA", "たべた"),
+            ("食べta", "食べた"),
+            ("html", "html"),
+            ("", ""),
+        ];
+        for (romaji, hira) in cases {
+            assert_eq!(romaji_to_hira(romaji), hira, "Wrong kana for '{}'", romaji);
+        }
+        for text in ["hi-tech", "e-mail", "x-ray"] {
+            assert!(!romaji_to_hira(text).contains('ー'), "'{}'", text);
+        }
+    }
+
     proptest! {
+        #[test]
+        fn romaji_to_hira_does_not_panic(s in any::<String>()) {
+            romaji_to_hira(&s);
+        }
+
+        #[test]
+        fn hira_to_kata_roundtrip(s in "[ぁ-ゖ]*") {
+            prop_assert_eq!(kata_to_hira(&hira_to_kata(&s)), s);
+        }
+
         #[test]
         fn non_katakana_stay_unchanged(s in any::<String>()) {
             let converted = kata_to_hira(&s);
@@ -0,0 +1,126 @@
+use crate::deinflect::{deinflect_detailed, Deinflection, RuleType};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
+/// A dictionary that knows which grammatical types its words can have,
+/// e.g. 待つ -> [`RuleType::V5T`]
+pub trait Lexicon {
+    /// Returns the types of `word`, or `None` if the word is not contained in the lexicon
+    fn lookup(&self, word: &str) -> Option<&[RuleType]>;
+}
+
+impl<S: BuildHasher> Lexicon for HashMap<String, Vec<RuleType>, S> {
+    fn lookup(&self, word: &str) -> Option<&[RuleType]> {
+        self.get(word).map(Vec::as_slice)
+    }
+}
+
+impl Lexicon for BTreeMap<String, Vec<RuleType>> {
+    fn lookup(&self, word: &str) -> Option<&[RuleType]> {
+        self.get(word).map(Vec::as_slice)
+    }
+}
+
+impl<L: Lexicon + ?Sized> Lexicon for &L {
+    fn lookup(&self, word: &str) -> Option<&[RuleType]> {
+        (**self).lookup(word)
+    }
+}
+
+/// Returns true if `deinflection` is a word of the lexicon with a fitting type.
+/// A word without any rules applied fits all types.
+pub(crate) fn is_in_lexicon(deinflection: &Deinflection, lexicon: &impl Lexicon) -> bool {
+    lexicon.lookup(&deinflection.word).is_some_and(|types| {
+        deinflection.rules.is_empty() || types.iter().any(|t| deinflection.types.contains(t))
+    })
+}
+
+/// Like [`deinflect_detailed`], but only returns the deinflections whose base form is
+/// contained in `lexicon` with a type that fits the rules that lead to it.
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::{deinflect_with_lexicon, RuleType};
+/// use std::collections::HashMap;
+///
+/// let lexicon = HashMap::from([
+///     ("待つ".to_string(), vec![RuleType::V5T]),
+///     ("待る".to_string(), vec![RuleType::AdjI]),
+/// ]);
+/// let deinflections = deinflect_with_lexicon("待った", &lexicon);
+/// assert!(deinflections.iter().all(|d| d.word == "待つ"));
+/// assert!(!deinflections.is_empty());
+/// ```
+pub fn deinflect_with_lexicon(word: &str, lexicon: &impl Lexicon) -> Vec<Deinflection<'static>> {
+    filter(deinflect_detailed(word), lexicon)
+}
+
+/// Removes the deinflections that aren't contained in `lexicon`, see [`deinflect_with_lexicon`]
+pub(crate) fn filter<'a>(
+    deinflections: Vec<Deinflection<'a>>,
+    lexicon: &impl Lexicon,
+) -> Vec<Deinflection<'a>> {
+    deinflections
+        .into_iter()
+        .filter(|deinflection| is_in_lexicon(deinflection, lexicon))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lexicon() -> HashMap<String, Vec<RuleType>> {
+        [
+            ("待つ", vec![RuleType::V5T]),
+            ("食べる", vec![RuleType::V1]),
+            ("早い", vec![RuleType::AdjI]),
+            // Wrong type on purpose, so that it must be pruned
+            ("待る", vec![RuleType::V1]),
+        ]
+        .into_iter()
+        .map(|(word, types)| (word.to_string(), types))
+        .collect()
+    }
+
+    fn words<'a>(deinflections: &'a [Deinflection]) -> Vec<&'a str> {
+        deinflections.iter().map(|d| d.word.as_str()).collect()
+    }
+
+    #[test]
+    fn test_prunes_unknown_words() {
+        let lexicon = lexicon();
+        let deinflections = deinflect_with_lexicon("待った", &lexicon);
+        let words = words(&deinflections);
+        assert!(words.contains(&"待つ"));
+        assert!(!words.contains(&"待う"));
+    }
+
+    #[test]
+    fn test_prunes_words_with_wrong_type() {
+        let lexicon = lexicon();
+        let deinflections = deinflect_with_lexicon("待った", &lexicon);
+        assert!(!words(&deinflections).contains(&"待る"));
+
+        // 待つ is v5t, so the rules for v5r verbs must not match
+        let lexicon = HashMap::from([("待つ".to_string(), vec![RuleType::V5R])]);
+        assert!(deinflect_with_lexicon("待った", &lexicon).is_empty());
+    }
+
+    #[test]
+    fn test_keeps_chains() {
+        let lexicon = lexicon();
+        let deinflections = deinflect_with_lexicon("食べさせられなかった", &lexicon);
+        assert!(!deinflections.is_empty());
+        assert!(deinflections.iter().all(|d| d.word == "食べる"));
+
+        let deinflections = deinflect_with_lexicon("早くない", &lexicon);
+        assert!(words(&deinflections).contains(&"早い"));
+    }
+
+    #[test]
+    fn test_empty_lexicon() {
+        let lexicon: BTreeMap<String, Vec<RuleType>> = BTreeMap::new();
+        assert!(deinflect_with_lexicon("待った", &lexicon).is_empty());
+    }
+}
@@ -1,6 +1,37 @@
 mod deinflect;
 mod deinflection_rules;
+mod deinflector;
+#[cfg(feature = "ffi")]
+mod ffi;
+mod inflect;
+mod inflection_kind;
 mod kata_to_hira;
+mod lexicon;
+mod normalize;
+#[cfg(feature = "python")]
+mod python;
+mod rank;
+mod rule_set;
+mod scan;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "json")]
+mod yomitan;
 
-pub use deinflect::deinflect;
-pub use kata_to_hira::kata_to_hira;
+pub use deinflect::{
+    deinflect, deinflect_detailed, deinflect_iter, deinflect_romaji, Deinflection,
+    DeinflectionRule, RuleType,
+};
+pub use deinflector::Deinflector;
+pub use inflect::{inflect, inflect_with_max_depth, Inflection, DEFAULT_MAX_INFLECTION_DEPTH};
+pub use inflection_kind::InflectionKind;
+pub use kata_to_hira::{hira_to_kata, kata_to_hira, romaji_to_hira};
+pub use lexicon::{deinflect_with_lexicon, Lexicon};
+pub use normalize::{expand_iteration_marks, expand_prolonged_sound_marks, fold_width, normalize};
+pub use rank::{deinflect_ranked, RankedDeinflection};
+#[cfg(feature = "json")]
+pub use rule_set::RuleSetError;
+pub use rule_set::{OwnedDeinflectionRule, RuleSet};
+pub use scan::{scan, scan_with, scan_with_lexicon, ScanMatch, MAX_SCAN_LENGTH};
+#[cfg(feature = "json")]
+pub use yomitan::{export_yomitan_transforms, import_yomitan_transforms, YomitanImport};
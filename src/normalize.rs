@@ -0,0 +1,375 @@
+use crate::deinflect::Deinflection;
+use crate::kata_to_hira::kata_to_hira;
+use std::iter;
+use std::ops::Range;
+
+/// The full-width counterparts of the half-width characters U+FF61..=U+FF9D
+const HALF_WIDTH_KATAKANA: &str = "。「」、・ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン";
+
+const UNVOICED: &str =
+    "かきくけこさしすせそたちつてとはひふへほうカキクケコサシスセソタチツテトハヒフヘホウ";
+const VOICED: &str =
+    "がぎぐげござじずぜぞだぢづでどばびぶべぼゔガギグゲゴザジズゼゾダヂヅデドバビブベボヴ";
+const SEMI_UNVOICED: &str = "はひふへほハヒフヘホ";
+const SEMI_VOICED: &str = "ぱぴぷぺぽパピプペポ";
+
+/// Returns the character of `to` at the position of `c` in `from`
+fn translate(c: char, from: &str, to: &str) -> Option<char> {
+    let i = from.chars().position(|f| f == c)?;
+    to.chars().nth(i)
+}
+
+fn voiced(c: char) -> Option<char> {
+    translate(c, UNVOICED, VOICED)
+}
+
+fn semi_voiced(c: char) -> Option<char> {
+    translate(c, SEMI_UNVOICED, SEMI_VOICED)
+}
+
+/// Returns the unvoiced counterpart of `c`, or `c` itself if it isn't voiced
+fn unvoiced(c: char) -> char {
+    translate(c, VOICED, UNVOICED)
+        .or_else(|| translate(c, SEMI_VOICED, SEMI_UNVOICED))
+        .unwrap_or(c)
+}
+
+/// The characters of a text that is being normalized, each with the byte offset of the
+/// character of the original text it was derived from
+type Mapped = Vec<(char, usize)>;
+
+fn mapped(text: &str) -> Mapped {
+    text.char_indices().map(|(i, c)| (c, i)).collect()
+}
+
+fn collect(chars: &[(char, usize)]) -> String {
+    chars.iter().map(|&(c, _)| c).collect()
+}
+
+fn fold_width_mapped(chars: Mapped) -> Mapped {
+    let mut result: Mapped = Vec::with_capacity(chars.len());
+    for (c, offset) in chars {
+        let folded = match c {
+            '\u{FF61}'..='\u{FF9D}' => HALF_WIDTH_KATAKANA
+                .chars()
+                .nth(c as usize - 0xFF61)
+                .unwrap_or(c),
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            '\u{3000}' => ' ',
+            // Voiced sound marks, both the half-width and the combining ones
+            '\u{FF9E}' | '\u{3099}' | '\u{FF9F}' | '\u{309A}' => {
+                let is_voiced = matches!(c, '\u{FF9E}' | '\u{3099}');
+                let previous = result.last().copied();
+                if let (true, Some(('ワ', previous_offset))) = (is_voiced, previous) {
+                    result.pop();
+                    result.extend([('ヴ', previous_offset), ('ァ', previous_offset)]);
+                    continue;
+                }
+                let composed = previous.and_then(|(previous, previous_offset)| {
+                    let composed = if is_voiced {
+                        voiced(previous)
+                    } else {
+                        semi_voiced(previous)
+                    };
+                    composed.map(|composed| (composed, previous_offset))
+                });
+                match composed {
+                    Some(composed) => {
+                        result.pop();
+                        result.push(composed);
+                        continue;
+                    }
+                    None if is_voiced => '゛',
+                    None => '゜',
+                }
+            }
+            'ヷ' | 'ヸ' | 'ヹ' | 'ヺ' => {
+                let small = match c {
+                    'ヷ' => 'ァ',
+                    'ヸ' => 'ィ',
+                    'ヹ' => 'ェ',
+                    _ => 'ォ',
+                };
+                result.extend([('ヴ', offset), (small, offset)]);
+                continue;
+            }
+            _ => c,
+        };
+        result.push((folded, offset));
+    }
+    result
+}
+
+/// Folds the width of the characters like NFKC does: half-width katakana become full-width
+/// (combining their voiced sound marks with the previous character, e.g. ｶﾞ -> ガ), full-width
+/// ASCII becomes ASCII and the ideographic space becomes a space.
+/// Additionally, ヷヸヹヺ are replaced by ヴァヴィヴェヴォ, which have hiragana counterparts.
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::fold_width;
+/// assert_eq!(fold_width("ﾀﾍﾞﾀ"), "タベタ");
+/// assert_eq!(fold_width("ＡＢＣ１２３"), "ABC123");
+/// assert_eq!(fold_width("ヷ"), "ヴァ");
+/// ```
+pub fn fold_width(text: &str) -> String {
+    collect(&fold_width_mapped(mapped(text)))
+}
+
+fn expand_iteration_marks_mapped(chars: Mapped) -> Mapped {
+    let mut result: Mapped = Vec::with_capacity(chars.len());
+    for (c, offset) in chars {
+        let expanded = match (c, result.last()) {
+            ('ゝ' | 'ヽ', Some(&(previous, _))) => unvoiced(previous),
+            ('ゞ' | 'ヾ', Some(&(previous, _))) => voiced(unvoiced(previous)).unwrap_or(previous),
+            _ => c,
+        };
+        result.push((expanded, offset));
+    }
+    result
+}
+
+/// Replaces the kana iteration marks ゝゞヽヾ by the character they repeat,
+/// e.g. いすゞ -> いすず or こゝろ -> こころ
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::expand_iteration_marks;
+/// assert_eq!(expand_iteration_marks("いすゞ"), "いすず");
+/// assert_eq!(expand_iteration_marks("バナヽ"), "バナナ");
+/// ```
+pub fn expand_iteration_marks(text: &str) -> String {
+    collect(&expand_iteration_marks_mapped(mapped(text)))
+}
+
+/// Returns the vowel of the kana `c` in the same script, e.g. ア for カ or い for ち
+fn vowel(c: char) -> Option<char> {
+    let hira = kata_to_hira(&c.to_string()).chars().next()?;
+    let vowel = if "あかさたなはまやらわがざだばぱぁゃゎ".contains(hira) {
+        'あ'
+    } else if "いきしちにひみりぎじぢびぴぃ".contains(hira) {
+        'い'
+    } else if "うくすつぬふむゆるぐずづぶぷぅゅゔ".contains(hira) {
+        'う'
+    } else if "えけせてねへめれげぜでべぺぇ".contains(hira) {
+        'え'
+    } else if "おこそとのほもよろをごぞどぼぽぉょ".contains(hira) {
+        'お'
+    } else {
+        return None;
+    };
+    if hira == c {
+        Some(vowel)
+    } else {
+        // The character is katakana
+        char::from_u32(vowel as u32 + 0x60)
+    }
+}
+
+/// Returns the kana that spells out a prolonged sound mark after `previous`, in the same script.
+/// With `long_vowels`, ー after お-row and え-row kana is spelled like the long vowels of
+/// 行こう and 先生, otherwise it repeats the vowel like in すげえ.
+fn prolonged_vowel(previous: char, long_vowels: bool) -> Option<char> {
+    let vowel = vowel(previous)?;
+    if !long_vowels {
+        return Some(vowel);
+    }
+    Some(match vowel {
+        'お' => 'う',
+        'え' => 'い',
+        'オ' => 'ウ',
+        'エ' => 'イ',
+        _ => vowel,
+    })
+}
+
+fn expand_prolonged_mapped(chars: &[(char, usize)], long_vowels: bool) -> Mapped {
+    let mut result: Mapped = Vec::with_capacity(chars.len());
+    for &(c, offset) in chars {
+        let expanded = match c {
+            'ー' => result
+                .last()
+                .and_then(|&(previous, _)| prolonged_vowel(previous, long_vowels))
+                .unwrap_or(c),
+            _ => c,
+        };
+        result.push((expanded, offset));
+    }
+    result
+}
+
+/// Replaces the prolonged sound mark ー by the kana it stands for: the vowel of the previous
+/// kana, except that it becomes う after お-row kana and い after え-row kana,
+/// e.g. カッコイー -> カッコイイ, 行こー -> 行こう or せんせー -> せんせい.
+/// Marks that don't follow a kana with a vowel are kept.
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::expand_prolonged_sound_marks;
+/// assert_eq!(expand_prolonged_sound_marks("カッコイー"), "カッコイイ");
+/// assert_eq!(expand_prolonged_sound_marks("でしょー"), "でしょう");
+/// assert_eq!(expand_prolonged_sound_marks("せんせーー"), "せんせいい");
+/// ```
+pub fn expand_prolonged_sound_marks(text: &str) -> String {
+    collect(&expand_prolonged_mapped(&mapped(text), true))
+}
+
+/// Applies [`fold_width`], [`expand_iteration_marks`] and [`expand_prolonged_sound_marks`],
+/// so that spelling variants deinflect like their standard spelling.
+/// Since this also changes dictionary forms like コーヒー, it isn't applied by
+/// [`deinflect`](crate::deinflect), see [`Deinflector::with_normalization`](crate::Deinflector::with_normalization).
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::{deinflect, normalize};
+/// assert_eq!(normalize("ﾀﾍﾞﾀ"), "タベタ");
+/// assert!(deinflect(&normalize("ﾀﾍﾞﾀ")).iter().any(|w| w == "たべる"));
+/// ```
+pub fn normalize(text: &str) -> String {
+    let folded = expand_iteration_marks_mapped(fold_width_mapped(mapped(text)));
+    collect(&expand_prolonged_mapped(&folded, true))
+}
+
+/// Maps the byte offsets of a normalized text back to the text it was normalized from
+#[derive(Debug, Clone)]
+pub(crate) struct OffsetMap {
+    /// The offset in the original text for every byte of the normalized text and its end
+    offsets: Vec<usize>,
+}
+
+impl OffsetMap {
+    fn new(chars: &[(char, usize)], original_len: usize) -> Self {
+        let mut offsets = Vec::with_capacity(4 * chars.len() + 1);
+        for &(c, offset) in chars {
+            offsets.extend(iter::repeat_n(offset, c.len_utf8()));
+        }
+        offsets.push(original_len);
+        Self { offsets }
+    }
+
+    /// Returns the byte range of the original text that `range` of the normalized text was
+    /// derived from
+    pub fn original_range(&self, range: Range<usize>) -> Range<usize> {
+        self.offsets[range.start]..self.offsets[range.end]
+    }
+
+    /// Translates the inflection span of a deinflection of the normalized text to the
+    /// original text
+    pub fn to_original<'a>(&self, mut deinflection: Deinflection<'a>) -> Deinflection<'a> {
+        deinflection.inflection_span = self.original_range(deinflection.inflection_span);
+        deinflection
+    }
+}
+
+/// Returns the spellings of `text` that are deinflected when normalization is enabled: the
+/// [`normalize`]d one and, if it differs, the one that spells ー after お-row and え-row kana
+/// with their own vowel, since slang like すげー (すげえ) is written that way.
+/// Both spellings have the same length, so a single [`OffsetMap`] maps them back to `text`.
+pub(crate) fn normalized_spellings(text: &str) -> (Vec<String>, OffsetMap) {
+    let folded = expand_iteration_marks_mapped(fold_width_mapped(mapped(text)));
+    let normalized = expand_prolonged_mapped(&folded, true);
+    let literal = collect(&expand_prolonged_mapped(&folded, false));
+    let offsets = OffsetMap::new(&normalized, text.len());
+    let normalized = collect(&normalized);
+    debug_assert_eq!(normalized.len(), literal.len());
+    if literal == normalized {
+        (vec![normalized], offsets)
+    } else {
+        (vec![normalized, literal], offsets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_fold_width() {
+        assert_eq!(fold_width("ﾀﾍﾞﾀ"), "タベタ");
+        assert_eq!(fold_width("ｶﾞｷﾞｸﾞｹﾞｺﾞ"), "ガギグゲゴ");
+        assert_eq!(fold_width("ﾊﾟﾋﾟﾌﾟﾍﾟﾎﾟ"), "パピプペポ");
+        assert_eq!(fold_width("ｳﾞｧｲｵﾘﾝ"), "ヴァイオリン");
+        assert_eq!(fold_width("ﾜﾞ"), "ヴァ");
+        assert_eq!(fold_width("｢ｺｰﾋｰ｣､｡"), "「コーヒー」、。");
+        // Combining voiced sound marks
+        assert_eq!(fold_width("か\u{3099}は\u{309A}"), "がぱ");
+        // Marks that can't be combined
+        assert_eq!(fold_width("ﾞあﾟ"), "゛あ゜");
+        assert_eq!(fold_width("ヷヸヹヺ"), "ヴァヴィヴェヴォ");
+        assert_eq!(fold_width("Ｒｕｓｔ　１"), "Rust 1");
+    }
+
+    #[test]
+    fn test_expand_iteration_marks() {
+        assert_eq!(expand_iteration_marks("こゝろ"), "こころ");
+        assert_eq!(expand_iteration_marks("たゞ"), "ただ");
+        assert_eq!(expand_iteration_marks("ぶゝ"), "ぶふ");
+        assert_eq!(expand_iteration_marks("ぶゞ"), "ぶぶ");
+        assert_eq!(expand_iteration_marks("スヾキ"), "スズキ");
+        assert_eq!(expand_iteration_marks("ゝ"), "ゝ");
+        // The kanji iteration mark is part of dictionary forms
+        assert_eq!(expand_iteration_marks("時々"), "時々");
+    }
+
+    #[test]
+    fn test_expand_prolonged_sound_marks() {
+        assert_eq!(expand_prolonged_sound_marks("カッコイー"), "カッコイイ");
+        assert_eq!(expand_prolonged_sound_marks("すげー"), "すげい");
+        assert_eq!(expand_prolonged_sound_marks("そーだ"), "そうだ");
+        assert_eq!(expand_prolonged_sound_marks("ちょー"), "ちょう");
+        assert_eq!(expand_prolonged_sound_marks("行こー"), "行こう");
+        assert_eq!(expand_prolonged_sound_marks("ケーキ"), "ケイキ");
+        assert_eq!(expand_prolonged_sound_marks("ソース"), "ソウス");
+        assert_eq!(expand_prolonged_sound_marks("ラーメン"), "ラアメン");
+        assert_eq!(expand_prolonged_sound_marks("ー"), "ー");
+        assert_eq!(expand_prolonged_sound_marks("んー"), "んー");
+        assert_eq!(expand_prolonged_sound_marks("漢ー"), "漢ー");
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("ｶｯｺｲｰ"), "カッコイイ");
+        assert_eq!(normalize("ｽｹﾞｰ"), "スゲイ");
+        assert_eq!(normalize("いすゞ"), "いすず");
+    }
+
+    #[test]
+    fn test_normalized_spellings() {
+        assert_eq!(normalized_spellings("食べた").0, vec!["食べた"]);
+        assert_eq!(normalized_spellings("ｶｯｺｲｰ").0, vec!["カッコイイ"]);
+        assert_eq!(normalized_spellings("すげー").0, vec!["すげい", "すげえ"]);
+        assert_eq!(normalized_spellings("行こー").0, vec!["行こう", "行こお"]);
+    }
+
+    #[test]
+    fn test_offset_map() {
+        let text = "ｶﾞｸﾞｶﾞﾀﾍﾞﾀ";
+        let (spellings, offsets) = normalized_spellings(text);
+        assert_eq!(spellings, vec!["ガグガタベタ"]);
+        // ガ comes from ｶﾞ
+        assert_eq!(&text[offsets.original_range(0..3)], "ｶﾞ");
+        assert_eq!(&text[offsets.original_range(15..18)], "ﾀ");
+        assert_eq!(&text[offsets.original_range(12..18)], "ﾍﾞﾀ");
+
+        let text = "Ｒｕｓｔヷー";
+        let (spellings, offsets) = normalized_spellings(text);
+        assert_eq!(spellings[0], "Rustヴァア");
+        assert_eq!(&text[offsets.original_range(4..10)], "ヷ");
+        assert_eq!(&text[offsets.original_range(10..13)], "ー");
+        assert_eq!(offsets.original_range(4..4), 12..12);
+    }
+
+    proptest! {
+        #[test]
+        fn hiragana_and_kanji_stay_unchanged(s in "[ぁ-ゖ一-龠]*") {
+            prop_assert_eq!(normalize(&s), s);
+        }
+
+        #[test]
+        fn normalize_is_idempotent(s in any::<String>()) {
+            let normalized = normalize(&s);
+            prop_assert_eq!(normalize(&normalized), normalized);
+        }
+    }
+}
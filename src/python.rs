@@ -0,0 +1,121 @@
+//! Python module, built with `maturin build --release`
+
+use crate::deinflect::RuleType;
+use pyo3::prelude::*;
+
+/// A deinflected word, see [`Deinflection`](crate::Deinflection)
+#[pyclass(name = "Deinflection", module = "jp_deinflector", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct PyDeinflection {
+    word: String,
+    types: Vec<RuleType>,
+    /// The English names of the inflections, ordered from the base form outwards
+    inflections: Vec<&'static str>,
+    /// The span of the input consumed by the inflections as `(start, end)` character offsets,
+    /// so that `word[start:end]` works in Python
+    inflection_span: (usize, usize),
+}
+
+#[pymethods]
+impl PyDeinflection {
+    fn __repr__(&self) -> String {
+        format!(
+            "Deinflection(word={:?}, types={:?}, inflections={:?})",
+            self.word, self.types, self.inflections
+        )
+    }
+}
+
+/// Returns the possible base forms of `word`, see [`deinflect`](crate::deinflect)
+#[pyfunction]
+fn deinflect(word: &str) -> Vec<String> {
+    crate::deinflect(word)
+}
+
+/// Returns the deinflections of `word`, see [`deinflect_detailed`](crate::deinflect_detailed)
+#[pyfunction]
+fn deinflect_detailed(word: &str) -> Vec<PyDeinflection> {
+    let char_offset = |byte_offset: usize| word[..byte_offset].chars().count();
+    crate::deinflect_detailed(word)
+        .into_iter()
+        .map(|deinflection| PyDeinflection {
+            inflections: deinflection
+                .inflections()
+                .map(|kind| kind.english_name())
+                .collect(),
+            inflection_span: (
+                char_offset(deinflection.inflection_span.start),
+                char_offset(deinflection.inflection_span.end),
+            ),
+            types: deinflection.types.to_vec(),
+            word: deinflection.word,
+        })
+        .collect()
+}
+
+/// Converts the katakana in `kata` to hiragana, see [`kata_to_hira`](crate::kata_to_hira)
+#[pyfunction]
+fn kata_to_hira(kata: &str) -> String {
+    crate::kata_to_hira(kata)
+}
+
+#[pymodule]
+fn jp_deinflector(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(deinflect, m)?)?;
+    m.add_function(wrap_pyfunction!(deinflect_detailed, m)?)?;
+    m.add_function(wrap_pyfunction!(kata_to_hira, m)?)?;
+    m.add_class::<RuleType>()?;
+    m.add_class::<PyDeinflection>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    /// Runs `code` with the module imported as `jp`
+    fn run(code: &str) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = PyModule::new(py, "jp_deinflector").unwrap();
+            jp_deinflector(&module).unwrap();
+            let locals = PyDict::new(py);
+            locals.set_item("jp", module).unwrap();
+            let code = std::ffi::CString::new(code).unwrap();
+            if let Err(error) = py.run(&code, None, Some(&locals)) {
+                panic!("{}", error);
+            }
+        });
+    }
+
+    #[test]
+    fn test_functions() {
+        run(r#"
+assert "待つ" in jp.deinflect("待った")
+assert jp.kata_to_hira("マッタ") == "まった"
+"#);
+    }
+
+    #[test]
+    fn test_deinflect_detailed() {
+        run(r#"
+word = "思ッタ"
+omou = next(d for d in jp.deinflect_detailed(word) if d.word == "思う")
+assert omou.types == [jp.RuleType.V5U]
+assert omou.inflections == ["past"]
+start, end = omou.inflection_span
+assert word[start:end] == "ッタ"
+assert repr(omou).startswith("Deinflection(word=\"思う\"")
+"#);
+    }
+
+    #[test]
+    fn test_rule_type() {
+        run(r#"
+assert jp.RuleType.V5T == jp.RuleType.V5T
+assert jp.RuleType.V5T != jp.RuleType.V1
+assert len({jp.RuleType.V5T, jp.RuleType.V5T}) == 1
+"#);
+    }
+}
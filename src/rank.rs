@@ -0,0 +1,318 @@
+use crate::deinflect::{deinflect_detailed, Deinflection, DeinflectionRule, RuleType};
+use crate::inflection_kind::InflectionKind;
+use crate::kata_to_hira::kata_to_hira;
+use fxhash::FxHashMap;
+
+/// A deinflected base form together with all the ways it was reached
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RankedDeinflection<'a> {
+    /// The deinflected (base) form
+    pub word: String,
+    /// How plausible the base form is, higher is better.
+    /// Only meaningful in comparison to the scores of other candidates for the same input.
+    pub score: f32,
+    /// All deinflections leading to `word`, the most plausible one first
+    pub derivations: Vec<Deinflection<'a>>,
+}
+
+impl RankedDeinflection<'_> {
+    /// Returns the union of the types of all derivations
+    pub fn types(&self) -> Vec<RuleType> {
+        let mut types = Vec::new();
+        for derivation in &self.derivations {
+            for t in derivation.types {
+                if !types.contains(t) {
+                    types.push(*t);
+                }
+            }
+        }
+        types
+    }
+}
+
+/// How unlikely it is that a rule of the given kind is the right explanation for a suffix
+fn kind_cost(kind: InflectionKind) -> f32 {
+    match kind {
+        // Slang and archaic forms are rare in most texts
+        InflectionKind::Ee
+        | InflectionKind::NSlang
+        | InflectionKind::Ki
+        | InflectionKind::Nu
+        | InflectionKind::Zaru
+        | InflectionKind::Mu
+        | InflectionKind::ClassicalPast
+        | InflectionKind::Keri
+        | InflectionKind::Perfective
+        | InflectionKind::Realis
+        | InflectionKind::Beshi
+        | InflectionKind::Nari => 2.5,
+        // A single kana matches these, so they produce lots of false positives
+        InflectionKind::MasuStem
+        | InflectionKind::Imperative
+        | InflectionKind::Attributive
+        | InflectionKind::Mi => 1.5,
+        // Auxiliaries like させる, られる, ない and いる stack on top of each other, so that
+        // undoing them is cheaper than a rule that changes the word class
+        InflectionKind::Causative
+        | InflectionKind::CausativePassive
+        | InflectionKind::Passive
+        | InflectionKind::PotentialOrPassive
+        | InflectionKind::Negative
+        | InflectionKind::Tai
+        | InflectionKind::Progressive
+        | InflectionKind::Shimau
+        | InflectionKind::Copula => 0.5,
+        _ => 1.0,
+    }
+}
+
+/// Returns true for the types of words that can be looked up in a dictionary, as opposed to
+/// te-forms like 読んで and words followed by the copula like 静かだ
+fn is_dictionary_form_type(rule_type: RuleType) -> bool {
+    !matches!(rule_type, RuleType::Iru | RuleType::Copula)
+}
+
+fn rule_cost(rule: &DeinflectionRule) -> f32 {
+    kind_cost(rule.kind)
+}
+
+/// Returns true for the characters of the CJK Unified Ideographs blocks and 々
+pub(crate) fn is_kanji(c: char) -> bool {
+    matches!(c, '\u{3005}' | '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{20000}'..='\u{2FA1F}')
+}
+
+/// Returns the number of kana after the last kanji of `word`, `None` if it has no kanji
+fn okurigana_len(word: &str) -> Option<usize> {
+    let (i, c) = word.char_indices().rfind(|&(_, c)| is_kanji(c))?;
+    Some(word[i + c.len_utf8()..].chars().count())
+}
+
+/// Returns the part of `input` that was not touched by the deinflection
+fn common_prefix<'a>(input: &'a str, word: &str) -> &'a str {
+    let len = input
+        .char_indices()
+        .zip(word.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or_else(|| input.len().min(word.len()), |((i, _), _)| i);
+    &input[..len]
+}
+
+fn score(input: &str, deinflection: &Deinflection) -> f32 {
+    let mut score = -deinflection.rules.iter().map(|r| rule_cost(r)).sum::<f32>();
+    if deinflection
+        .types
+        .iter()
+        .any(|&rule_type| is_dictionary_form_type(rule_type))
+    {
+        // Outweighs the te-form rule that leads from 食べて to 食べる
+        score += 1.5;
+    }
+
+    match common_prefix(input, &deinflection.word).chars().last() {
+        Some(c) if is_kanji(c) => score += 0.5,
+        Some(_) => {}
+        // Nothing of the input is left, e.g. た -> る
+        None => score -= 1.0,
+    }
+
+    // Dictionary forms rarely have more than two kana after their kanji, unlike the auxiliary
+    // forms on the way to them, e.g. 食べさせられない instead of 食べる
+    if let Some(len) = okurigana_len(&deinflection.word) {
+        score -= 0.75 * len.saturating_sub(2) as f32;
+    }
+    score
+}
+
+/// Returns the deinflections of `word` with identical base forms merged, sorted from the most
+/// to the least plausible one.
+/// The plausibility takes into account how long the chains of inflections are, how rare the
+/// applied inflections are, whether the candidate is a dictionary form rather than an
+/// auxiliary form on the way to one (judging by its type and the number of kana after its
+/// kanji) and whether the untouched stem of the input ends with a kanji.
+/// Candidates with equal scores keep the order of [`deinflect`](crate::deinflect).
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::deinflect_ranked;
+/// let ranked = deinflect_ranked("食べさせられなかった");
+/// assert_eq!(ranked[0].word, "食べる");
+/// assert!(ranked.iter().filter(|r| r.word == "食べる").count() == 1);
+/// ```
+pub fn deinflect_ranked(word: &str) -> Vec<RankedDeinflection<'static>> {
+    rank(word, deinflect_detailed(word))
+}
+
+/// Merges and sorts the `deinflections` of `word`, see [`deinflect_ranked`]
+pub(crate) fn rank<'a>(
+    word: &str,
+    deinflections: Vec<Deinflection<'a>>,
+) -> Vec<RankedDeinflection<'a>> {
+    let input = kata_to_hira(word);
+
+    let mut ranked: Vec<RankedDeinflection> = Vec::new();
+    let mut indices: FxHashMap<String, usize> = FxHashMap::default();
+    for deinflection in deinflections {
+        let derivation_score = score(&input, &deinflection);
+        match indices.get(&deinflection.word) {
+            Some(&i) => {
+                let candidate = &mut ranked[i];
+                if derivation_score > candidate.score {
+                    candidate.score = derivation_score;
+                    candidate.derivations.insert(0, deinflection);
+                } else {
+                    candidate.derivations.push(deinflection);
+                }
+            }
+            None => {
+                indices.insert(deinflection.word.clone(), ranked.len());
+                ranked.push(RankedDeinflection {
+                    word: deinflection.word.clone(),
+                    score: derivation_score,
+                    derivations: vec![deinflection],
+                });
+            }
+        }
+    }
+
+    // Being reachable in several ways makes a candidate slightly more plausible
+    for candidate in &mut ranked {
+        candidate.score += 0.1 * (candidate.derivations.len() - 1) as f32;
+    }
+    ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deinflection_rules::{all_rules, CLASSICAL_RULES};
+
+    fn position(ranked: &[RankedDeinflection], word: &str) -> usize {
+        ranked.iter().position(|r| r.word == word).unwrap()
+    }
+
+    #[test]
+    fn test_merges_identical_words() {
+        let ranked = deinflect_ranked("来させる");
+        let kuru: Vec<_> = ranked.iter().filter(|r| r.word == "来る").collect();
+        assert_eq!(kuru.len(), 1);
+        assert!(kuru[0].derivations.len() > 1);
+        assert!(kuru[0].derivations.iter().all(|d| d.word == "来る"));
+
+        let plain = deinflect_detailed("来させる");
+        let count = plain.iter().filter(|d| d.word == "来る").count();
+        assert_eq!(kuru[0].derivations.len(), count);
+    }
+
+    #[test]
+    fn test_best_derivation_first() {
+        let input = "来させる";
+        for candidate in deinflect_ranked(input) {
+            let best = score(input, &candidate.derivations[0]);
+            assert!(candidate
+                .derivations
+                .iter()
+                .all(|d| score(input, d) <= best));
+        }
+    }
+
+    #[test]
+    fn test_prefers_short_chains() {
+        // The past of 食べる is more likely than the past of the potential of 食ぶ
+        let ranked = deinflect_ranked("食べた");
+        assert_eq!(ranked[0].word, "食べる");
+        assert!(position(&ranked, "食べる") < position(&ranked, "食ぶ"));
+    }
+
+    #[test]
+    fn test_prefers_lemmas_over_auxiliary_forms() {
+        let ranked = deinflect_ranked("食べさせられなかった");
+        assert_eq!(ranked[0].word, "食べる");
+        for intermediate in ["食べさせられない", "食べさせられる", "食べさせる"]
+        {
+            assert!(position(&ranked, "食べる") < position(&ranked, intermediate));
+        }
+
+        let ranked = deinflect_ranked("食べている");
+        assert_eq!(ranked[0].word, "食べる");
+        assert!(position(&ranked, "食べる") < position(&ranked, "食べて"));
+
+        let ranked = deinflect_ranked("静かだった");
+        assert_eq!(ranked[0].word, "静か");
+        assert!(position(&ranked, "静か") < position(&ranked, "静かだ"));
+    }
+
+    #[test]
+    fn test_longer_chains_are_not_rewarded() {
+        // Every rule makes a derivation less plausible
+        for (_, rules) in all_rules().chain(CLASSICAL_RULES) {
+            assert!(rules.iter().all(|rule| rule_cost(rule) > 0.0));
+        }
+
+        // 食ぶ takes the same path as 食べる plus the potential, so it must not win
+        let ranked = deinflect_ranked("食べさせられなかった");
+        let taberu = &ranked[position(&ranked, "食べる")];
+        let tabu = &ranked[position(&ranked, "食ぶ")];
+        assert_eq!(taberu.derivations[0].rules.len(), 4);
+        assert_eq!(tabu.derivations[0].rules.len(), 5);
+        assert!(taberu.score > tabu.score);
+        for candidate in &ranked {
+            if candidate.derivations[0].rules.len() > 4 {
+                assert!(
+                    candidate.score < taberu.score,
+                    "{} ranks too high",
+                    candidate.word
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_prefers_common_inflections() {
+        // The negative of 寒い is more likely than the imperative of 寒くなる
+        let ranked = deinflect_ranked("寒くない");
+        assert_eq!(ranked[0].word, "寒い");
+        assert!(position(&ranked, "寒い") < position(&ranked, "寒くなる"));
+
+        // A masu stem is less likely than a negative
+        let ranked = deinflect_ranked("知らない");
+        assert!(position(&ranked, "知る") < position(&ranked, "知らないる"));
+    }
+
+    #[test]
+    fn test_prefers_kanji_stems() {
+        assert!(
+            score(
+                "待った",
+                &Deinflection {
+                    word: "待つ".to_string(),
+                    types: &[RuleType::V5T],
+                    rules: vec![],
+                    inflection_span: 3..9,
+                }
+            ) > score(
+                "まった",
+                &Deinflection {
+                    word: "まつ".to_string(),
+                    types: &[RuleType::V5T],
+                    rules: vec![],
+                    inflection_span: 3..9,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_scores_are_sorted() {
+        let ranked = deinflect_ranked("走らされていました");
+        assert!(ranked.windows(2).all(|w| w[0].score >= w[1].score));
+    }
+
+    #[test]
+    fn test_types_union() {
+        let ranked = deinflect_ranked("待った");
+        let matsu = &ranked[position(&ranked, "待つ")];
+        assert_eq!(matsu.types(), vec![RuleType::V5T]);
+    }
+}
@@ -0,0 +1,642 @@
+use crate::deinflect::{DeinflectionRule, RuleType};
+use crate::deinflection_rules::{
+    all_rules, get_classical_rules, get_colloquial_rules, get_deinflection_rules, CLASSICAL_RULES,
+    COLLOQUIAL_RULES, MAX_SUFFIX_LENGTH,
+};
+use crate::inflection_kind::InflectionKind;
+use fxhash::FxHashMap;
+
+/// A [`DeinflectionRule`] that owns its data, so that it can be created at runtime
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedDeinflectionRule {
+    pub kana_out: String,
+    pub rules_in: Vec<RuleType>,
+    pub rules_out: Vec<RuleType>,
+    /// The inflection that this rule undoes
+    pub kind: InflectionKind,
+}
+
+impl OwnedDeinflectionRule {
+    pub fn new(
+        kana_out: impl Into<String>,
+        rules_in: impl Into<Vec<RuleType>>,
+        rules_out: impl Into<Vec<RuleType>>,
+        kind: InflectionKind,
+    ) -> Self {
+        Self {
+            kana_out: kana_out.into(),
+            rules_in: rules_in.into(),
+            rules_out: rules_out.into(),
+            kind,
+        }
+    }
+
+    /// Returns a view of the rule that borrows from it
+    pub fn as_rule(&self) -> DeinflectionRule<'_> {
+        DeinflectionRule {
+            kana_out: &self.kana_out,
+            rules_in: &self.rules_in,
+            rules_out: &self.rules_out,
+            kind: self.kind,
+        }
+    }
+}
+
+impl From<DeinflectionRule<'_>> for OwnedDeinflectionRule {
+    fn from(rule: DeinflectionRule<'_>) -> Self {
+        Self {
+            kana_out: rule.kana_out.to_string(),
+            rules_in: rule.rules_in.to_vec(),
+            rules_out: rule.rules_out.to_vec(),
+            kind: rule.kind,
+        }
+    }
+}
+
+/// A table of deinflection rules that is built at runtime, e.g. to add dialectal rules.
+/// Use it with a [`Deinflector`](crate::Deinflector).
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::{Deinflector, InflectionKind, OwnedDeinflectionRule, RuleSet, RuleType};
+///
+/// // Kansai-ben negative, e.g. 行かへん -> 行く
+/// let mut rules = RuleSet::builtin();
+/// rules.insert(
+///     "かへん",
+///     OwnedDeinflectionRule::new("く", [], [RuleType::V5K, RuleType::V5KS], InflectionKind::Negative),
+/// );
+/// let deinflector = Deinflector::with_rules(rules);
+/// assert!(deinflector.deinflect("行かへん").iter().any(|w| w == "行く"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RuleSet {
+    rules: FxHashMap<String, Vec<OwnedDeinflectionRule>>,
+    /// The length of the longest suffix in characters
+    max_suffix_length: usize,
+}
+
+impl RuleSet {
+    /// Creates an empty rule set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a rule set containing a copy of the built-in rules, which can then be extended.
+    /// The colloquial rules are included, see [`RuleSet::remove_colloquial`].
+    pub fn builtin() -> Self {
+        let mut rule_set = Self::new();
+        for (kana_in, rules) in all_rules() {
+            // Suffixes this long are never looked up by the built-in table either
+            if kana_in.chars().count() > MAX_SUFFIX_LENGTH {
+                continue;
+            }
+            for rule in rules.iter() {
+                rule_set.insert(*kana_in, (*rule).into());
+            }
+        }
+        rule_set
+    }
+
+    /// Adds a rule that replaces the suffix `kana_in` by the rule's `kana_out`.
+    /// Rules for the same suffix are applied in the order in which they were inserted.
+    pub fn insert(&mut self, kana_in: impl Into<String>, rule: OwnedDeinflectionRule) {
+        let kana_in = kana_in.into();
+        self.max_suffix_length = self.max_suffix_length.max(kana_in.chars().count());
+        self.rules.entry(kana_in).or_default().push(rule);
+    }
+
+    /// Removes the built-in rules for colloquial contractions like 食べてる, 見とく or 行かなきゃ,
+    /// e.g. for processing formal text
+    ///
+    /// # Examples
+    /// ```
+    /// use jp_deinflector::RuleSet;
+    /// let mut rules = RuleSet::builtin();
+    /// rules.remove_colloquial();
+    /// assert!(rules.get("きゃ").is_none());
+    /// assert!(rules.get("ければ").is_some());
+    /// ```
+    pub fn remove_colloquial(&mut self) {
+        self.remove_builtin(get_colloquial_rules);
+    }
+
+    /// Adds the built-in rules for colloquial contractions that [`RuleSet::remove_colloquial`]
+    /// removes. Rules that are already part of the rule set are not added again.
+    ///
+    /// # Examples
+    /// ```
+    /// use jp_deinflector::RuleSet;
+    /// let mut rules = RuleSet::builtin();
+    /// rules.remove_colloquial();
+    /// rules.add_colloquial();
+    /// assert!(rules.get("きゃ").is_some());
+    /// assert_eq!(rules.len(), RuleSet::builtin().len());
+    /// ```
+    pub fn add_colloquial(&mut self) {
+        self.add_builtin(COLLOQUIAL_RULES);
+    }
+
+    /// Adds the built-in rules for classical (bungo) Japanese, like 知らざりき, 受くる or
+    /// 行くべし, which [`RuleSet::builtin`] doesn't contain. Rules that are already part of
+    /// the rule set are not added again.
+    ///
+    /// # Examples
+    /// ```
+    /// use jp_deinflector::{Deinflector, RuleSet};
+    /// let mut rules = RuleSet::builtin();
+    /// rules.add_classical();
+    /// let deinflector = Deinflector::with_rules(rules);
+    /// assert!(deinflector.deinflect("知らざりき").iter().any(|w| w == "知る"));
+    /// ```
+    pub fn add_classical(&mut self) {
+        self.add_builtin(CLASSICAL_RULES);
+    }
+
+    /// Adds the rules of one of the built-in tables that aren't part of the rule set yet
+    fn add_builtin(&mut self, table: &[(&str, &[DeinflectionRule<'static>])]) {
+        for (kana_in, rules) in table {
+            for rule in rules.iter() {
+                if !self
+                    .get(kana_in)
+                    .unwrap_or_default()
+                    .contains(&(*rule).into())
+                {
+                    self.insert(*kana_in, (*rule).into());
+                }
+            }
+        }
+    }
+
+    /// Removes the built-in classical rules that [`RuleSet::add_classical`] adds
+    pub(crate) fn remove_classical(&mut self) {
+        self.remove_builtin(get_classical_rules);
+    }
+
+    /// Removes the rules of one of the built-in tables
+    fn remove_builtin(&mut self, table: fn(&str) -> Option<&'static [DeinflectionRule<'static>]>) {
+        self.rules.retain(|kana_in, rules| {
+            if let Some(builtin) = table(kana_in) {
+                rules.retain(|rule| !builtin.contains(&rule.as_rule()));
+            }
+            !rules.is_empty()
+        });
+    }
+
+    /// Returns the rules for the suffix `kana_in`
+    pub fn get(&self, kana_in: &str) -> Option<&[OwnedDeinflectionRule]> {
+        self.rules.get(kana_in).map(Vec::as_slice)
+    }
+
+    /// Returns an iterator over all (suffix, rule) pairs, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &OwnedDeinflectionRule)> {
+        self.rules
+            .iter()
+            .flat_map(|(kana_in, rules)| rules.iter().map(move |rule| (kana_in.as_str(), rule)))
+    }
+
+    /// Returns the number of rules
+    pub fn len(&self) -> usize {
+        self.rules.values().map(Vec::len).sum()
+    }
+
+    /// Returns true if the rule set doesn't contain any rules
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+/// The rules behind the free functions like [`deinflect`](crate::deinflect)
+pub(crate) static BUILTIN_RULES: Rules = Rules::Builtin {
+    colloquial: true,
+    classical: false,
+};
+
+/// The rules a [`Deinflector`](crate::Deinflector) applies
+#[derive(Debug, Clone)]
+pub(crate) enum Rules {
+    /// The static tables of `deinflection_rules.rs`, with or without the optional ones
+    Builtin {
+        colloquial: bool,
+        classical: bool,
+    },
+    Custom(RuleSet),
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        BUILTIN_RULES.clone()
+    }
+}
+
+impl Rules {
+    /// Calls `f` with every rule for the suffix `kana_in`
+    #[inline]
+    pub fn for_each_rule<'a>(&'a self, kana_in: &str, mut f: impl FnMut(DeinflectionRule<'a>)) {
+        match self {
+            &Rules::Builtin {
+                colloquial,
+                classical,
+            } => {
+                for rule in get_deinflection_rules(kana_in).unwrap_or_default() {
+                    f(*rule);
+                }
+                if colloquial {
+                    for rule in get_colloquial_rules(kana_in).unwrap_or_default() {
+                        f(*rule);
+                    }
+                }
+                if classical {
+                    for rule in get_classical_rules(kana_in).unwrap_or_default() {
+                        f(*rule);
+                    }
+                }
+            }
+            Rules::Custom(rule_set) => {
+                for rule in rule_set.get(kana_in).unwrap_or_default() {
+                    f(rule.as_rule());
+                }
+            }
+        }
+    }
+
+    /// The length of the longest suffix that has rules, in characters
+    #[inline]
+    pub fn max_suffix_length(&self) -> usize {
+        match self {
+            Rules::Builtin { .. } => MAX_SUFFIX_LENGTH,
+            Rules::Custom(rule_set) => rule_set.max_suffix_length,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+mod json {
+    use super::*;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+    use std::fmt;
+
+    /// An error that occurred while loading a [`RuleSet`]
+    #[derive(Debug)]
+    pub enum RuleSetError {
+        /// The input is not valid JSON of the expected format
+        Json(serde_json::Error),
+        /// A rule uses a type that doesn't correspond to any [`RuleType`]
+        UnknownRuleType(String),
+        /// A rule uses a reason that doesn't correspond to any [`InflectionKind`]
+        UnknownInflection(String),
+    }
+
+    impl fmt::Display for RuleSetError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                RuleSetError::Json(err) => write!(f, "invalid rule set: {err}"),
+                RuleSetError::UnknownRuleType(t) => write!(f, "unknown rule type '{t}'"),
+                RuleSetError::UnknownInflection(name) => write!(f, "unknown inflection '{name}'"),
+            }
+        }
+    }
+
+    impl std::error::Error for RuleSetError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                RuleSetError::Json(err) => Some(err),
+                _ => None,
+            }
+        }
+    }
+
+    impl From<serde_json::Error> for RuleSetError {
+        fn from(err: serde_json::Error) -> Self {
+            RuleSetError::Json(err)
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct YomichanRule {
+        kana_in: String,
+        kana_out: String,
+        rules_in: Vec<String>,
+        rules_out: Vec<String>,
+    }
+
+    /// Returns the godan class of a verb ending in `kana`, e.g. V5T for つ.
+    /// If the ending isn't one of a godan verb, all godan classes are returned.
+    pub(crate) fn godan_types(kana: &str) -> Vec<RuleType> {
+        let rule_type = match kana.chars().last() {
+            Some('う') => RuleType::V5U,
+            Some('く') => RuleType::V5K,
+            Some('ぐ') => RuleType::V5G,
+            Some('す') => RuleType::V5S,
+            Some('つ') => RuleType::V5T,
+            Some('ぬ') => RuleType::V5N,
+            Some('ぶ') => RuleType::V5B,
+            Some('む') => RuleType::V5M,
+            Some('る') => RuleType::V5R,
+            _ => {
+                return RuleType::ALL
+                    .iter()
+                    .copied()
+                    .filter(|t| t.is_godan())
+                    .collect()
+            }
+        };
+        vec![rule_type]
+    }
+
+    /// Converts the rule types of Yomichan into ours.
+    /// Yomichan doesn't distinguish godan classes, so "v5" is narrowed down using the ending
+    /// of the verb if possible.
+    fn rule_types(tags: &[String], kana: &str) -> Result<Vec<RuleType>, RuleSetError> {
+        let mut types = Vec::new();
+        for tag in tags {
+            match tag.as_str() {
+                "iru" => types.push(RuleType::Iru),
+                // Yomichan's suru verbs, which JMdict splits into vs-i and vs-s
+                "vs" => types.push(RuleType::Vs),
+                "v5" => types.extend(godan_types(kana)),
+                _ => types.push(
+                    RuleType::from_pos_tag(tag)
+                        .ok_or_else(|| RuleSetError::UnknownRuleType(tag.clone()))?,
+                ),
+            }
+        }
+        Ok(types)
+    }
+
+    /// Converts the name of a Yomichan reason into an [`InflectionKind`]. Yomichan names most of
+    /// them like we do, except for a few older names.
+    fn inflection_kind(reason: &str) -> Option<InflectionKind> {
+        match reason {
+            "noun" => Some(InflectionKind::Sa),
+            "-e" => Some(InflectionKind::Ee),
+            _ => InflectionKind::from_english_name(reason),
+        }
+    }
+
+    impl RuleSet {
+        /// Loads a rule set from the `deinflect.json` format of Yomichan, which maps the names
+        /// of inflections to lists of rules:
+        /// ```json
+        /// { "past": [{ "kanaIn": "った", "kanaOut": "つ", "rulesIn": [], "rulesOut": ["v5"] }] }
+        /// ```
+        /// The names are the English names of [`InflectionKind`], apart from Yomichan's "noun"
+        /// for [`InflectionKind::Sa`] and "-e" for [`InflectionKind::Ee`].
+        ///
+        /// # Examples
+        /// ```
+        /// use jp_deinflector::{Deinflector, RuleSet};
+        /// let json = r#"{ "past": [{ "kanaIn": "った", "kanaOut": "つ", "rulesIn": [], "rulesOut": ["v5"] }] }"#;
+        /// let deinflector = Deinflector::with_rules(RuleSet::from_yomichan_json(json).unwrap());
+        /// assert_eq!(deinflector.deinflect("待った"), vec!["待つ"]);
+        /// ```
+        pub fn from_yomichan_json(json: &str) -> Result<Self, RuleSetError> {
+            // A BTreeMap gives the rules a deterministic order
+            let reasons: BTreeMap<String, Vec<YomichanRule>> = serde_json::from_str(json)?;
+
+            let mut rule_set = Self::new();
+            for (reason, rules) in reasons {
+                let kind =
+                    inflection_kind(&reason).ok_or(RuleSetError::UnknownInflection(reason))?;
+                for rule in rules {
+                    let rules_in = rule_types(&rule.rules_in, &rule.kana_in)?;
+                    let rules_out = rule_types(&rule.rules_out, &rule.kana_out)?;
+                    rule_set.insert(
+                        rule.kana_in,
+                        OwnedDeinflectionRule::new(rule.kana_out, rules_in, rules_out, kind),
+                    );
+                }
+            }
+            Ok(rule_set)
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+pub(crate) use json::godan_types;
+#[cfg(feature = "json")]
+pub use json::RuleSetError;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deinflector::Deinflector;
+
+    #[test]
+    fn test_builtin_matches_static_table() {
+        let rule_set = RuleSet::builtin();
+        assert_eq!(rule_set.max_suffix_length, MAX_SUFFIX_LENGTH);
+        for (kana_in, rule) in rule_set.iter() {
+            let rules = get_deinflection_rules(kana_in).unwrap_or_default();
+            let colloquial = get_colloquial_rules(kana_in).unwrap_or_default();
+            assert!(rules.contains(&rule.as_rule()) || colloquial.contains(&rule.as_rule()));
+        }
+
+        let custom = Deinflector::with_rules(rule_set);
+        for word in [
+            "食べさせられなかった",
+            "静かではありませんでした",
+            "行っちゃった",
+        ] {
+            let mut expected = Deinflector::new().deinflect(word);
+            let mut actual = custom.deinflect(word);
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        let mut rule_set = RuleSet::new();
+        assert!(rule_set.is_empty());
+        rule_set.insert(
+            "へん",
+            OwnedDeinflectionRule::new("ない", [], [RuleType::AdjI], InflectionKind::Negative),
+        );
+        rule_set.insert(
+            "ない",
+            OwnedDeinflectionRule::new(
+                "る",
+                [RuleType::AdjI],
+                [RuleType::V1],
+                InflectionKind::Negative,
+            ),
+        );
+        assert_eq!(rule_set.len(), 2);
+        assert_eq!(rule_set.max_suffix_length, 2);
+
+        let deinflector = Deinflector::with_rules(rule_set);
+        let words = deinflector.deinflect("食べへん");
+        assert_eq!(words, vec!["食べない", "食べる"]);
+        // Built-in rules aren't part of a custom rule set
+        assert!(deinflector.deinflect("食べた").is_empty());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_yomichan_json() {
+        let json = r#"{
+            "negative": [
+                { "kanaIn": "かない", "kanaOut": "く", "rulesIn": ["adj-i"], "rulesOut": ["v5"] },
+                { "kanaIn": "ない", "kanaOut": "る", "rulesIn": ["adj-i"], "rulesOut": ["v1", "vk"] }
+            ],
+            "past": [
+                { "kanaIn": "かった", "kanaOut": "い", "rulesIn": [], "rulesOut": ["adj-i"] }
+            ]
+        }"#;
+        let rule_set = RuleSet::from_yomichan_json(json).unwrap();
+        assert_eq!(rule_set.len(), 3);
+        assert_eq!(
+            rule_set.get("かない").unwrap()[0],
+            OwnedDeinflectionRule::new(
+                "く",
+                [RuleType::AdjI],
+                [RuleType::V5K],
+                InflectionKind::Negative
+            )
+        );
+
+        let deinflector = Deinflector::with_rules(rule_set);
+        let deinflections = deinflector.deinflect_detailed("書かなかった");
+        let kaku = deinflections.iter().find(|d| d.word == "書く").unwrap();
+        assert_eq!(
+            kaku.inflections().collect::<Vec<_>>(),
+            vec![InflectionKind::Negative, InflectionKind::Past]
+        );
+    }
+
+    /// An excerpt of Yomichan's `ext/data/deinflect.json`
+    #[cfg(feature = "json")]
+    const YOMICHAN_DEINFLECT_JSON: &str = r#"{
+    "-ba": [
+        {
+            "kanaIn": "ければ",
+            "kanaOut": "い",
+            "rulesIn": [],
+            "rulesOut": ["adj-i"]
+        },
+        {
+            "kanaIn": "えば",
+            "kanaOut": "う",
+            "rulesIn": [],
+            "rulesOut": ["v5"]
+        },
+        {
+            "kanaIn": "れば",
+            "kanaOut": "る",
+            "rulesIn": [],
+            "rulesOut": ["v1", "v5", "vk", "vs"]
+        }
+    ],
+    "-e": [
+        {
+            "kanaIn": "ねえ",
+            "kanaOut": "ない",
+            "rulesIn": [],
+            "rulesOut": ["adj-i"]
+        }
+    ],
+    "-te": [
+        {
+            "kanaIn": "くて",
+            "kanaOut": "い",
+            "rulesIn": [],
+            "rulesOut": ["adj-i"]
+        },
+        {
+            "kanaIn": "して",
+            "kanaOut": "する",
+            "rulesIn": ["iru"],
+            "rulesOut": ["vs"]
+        }
+    ],
+    "noun": [
+        {
+            "kanaIn": "さ",
+            "kanaOut": "い",
+            "rulesIn": [],
+            "rulesOut": ["adj-i"]
+        }
+    ],
+    "past": [
+        {
+            "kanaIn": "かった",
+            "kanaOut": "い",
+            "rulesIn": [],
+            "rulesOut": ["adj-i"]
+        },
+        {
+            "kanaIn": "した",
+            "kanaOut": "する",
+            "rulesIn": [],
+            "rulesOut": ["vs"]
+        },
+        {
+            "kanaIn": "来た",
+            "kanaOut": "来る",
+            "rulesIn": [],
+            "rulesOut": ["vk"]
+        }
+    ],
+    "progressive or perfect": [
+        {
+            "kanaIn": "ている",
+            "kanaOut": "て",
+            "rulesIn": ["v1"],
+            "rulesOut": ["iru"]
+        }
+    ]
+}"#;
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_yomichan_deinflect_json() {
+        let rule_set = RuleSet::from_yomichan_json(YOMICHAN_DEINFLECT_JSON).unwrap();
+        assert_eq!(rule_set.len(), 11);
+        assert_eq!(rule_set.get("さ").unwrap()[0].kind, InflectionKind::Sa);
+        assert_eq!(rule_set.get("ねえ").unwrap()[0].kind, InflectionKind::Ee);
+        assert_eq!(
+            rule_set.get("れば").unwrap()[0].rules_out,
+            vec![RuleType::V1, RuleType::V5R, RuleType::Vk, RuleType::Vs]
+        );
+
+        let deinflector = Deinflector::with_rules(rule_set);
+        for (word, expected) in [
+            ("勉強した", "勉強する"),
+            ("勉強している", "勉強する"),
+            ("勉強すれば", "勉強する"),
+            ("高さ", "高い"),
+            ("知らねえ", "知らない"),
+        ] {
+            assert!(
+                deinflector.deinflect(word).iter().any(|w| w == expected),
+                "{word}"
+            );
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_yomichan_json_errors() {
+        let unknown_type = r#"{ "past": [{ "kanaIn": "た", "kanaOut": "る", "rulesIn": [], "rulesOut": ["v9"] }] }"#;
+        assert!(matches!(
+            RuleSet::from_yomichan_json(unknown_type),
+            Err(RuleSetError::UnknownRuleType(t)) if t == "v9"
+        ));
+
+        let unknown_reason = r#"{ "pasta": [] }"#;
+        assert!(matches!(
+            RuleSet::from_yomichan_json(unknown_reason),
+            Err(RuleSetError::UnknownInflection(name)) if name == "pasta"
+        ));
+
+        assert!(matches!(
+            RuleSet::from_yomichan_json("[]"),
+            Err(RuleSetError::Json(_))
+        ));
+    }
+}
@@ -0,0 +1,309 @@
+use crate::deinflect::{deinflect_with_rules, Deinflection};
+use crate::kata_to_hira::kata_to_hira;
+use crate::lexicon::{is_in_lexicon, Lexicon};
+use crate::normalize::{expand_iteration_marks, fold_width, normalized_spellings};
+use crate::rule_set::{Rules, BUILTIN_RULES};
+use fxhash::FxHashSet;
+use std::iter;
+use std::ops::Range;
+
+/// The maximum number of characters that [`scan`] looks at, longer words are never matched
+pub const MAX_SCAN_LENGTH: usize = 16;
+
+/// The longest word found by [`scan`] or [`scan_with`]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ScanMatch<'a> {
+    /// The byte span of the matched word in the scanned text
+    pub span: Range<usize>,
+    /// The deinflections of the matched word that were accepted, in the order of
+    /// [`deinflect`](crate::deinflect). The word itself comes first if it was accepted as it is,
+    /// in which case its deinflection has no rules and no types. It is tried as it appears in
+    /// the text, in hiragana and, with normalization, in its normalized spellings.
+    pub deinflections: Vec<Deinflection<'a>>,
+}
+
+/// Scans `text` starting at the byte offset `start` and returns the longest word that
+/// `is_known` accepts one of the deinflections of.
+/// Prefixes of up to [`MAX_SCAN_LENGTH`] characters are tried from the longest to the shortest,
+/// each of them is passed to `is_known` as it is (without any rules) and with all of its
+/// deinflections.
+/// Returns `None` if no prefix is accepted or if `start` is not a character boundary of `text`.
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::{scan_with, RuleType};
+/// let text = "昨日映画を見に行ってしまったんだ";
+/// let start = text.find('行').unwrap();
+/// let found = scan_with(text, start, |d| d.word == "行く" && d.types.contains(&RuleType::V5KS));
+/// let found = found.unwrap();
+/// assert_eq!(&text[found.span], "行ってしまった");
+/// assert_eq!(found.deinflections[0].word, "行く");
+/// ```
+pub fn scan_with(
+    text: &str,
+    start: usize,
+    is_known: impl FnMut(&Deinflection) -> bool,
+) -> Option<ScanMatch<'static>> {
+    scan_with_rules(&BUILTIN_RULES, false, text, start, is_known)
+}
+
+/// Like [`scan_with`], but accepts the deinflections whose base form is contained in `lexicon`
+/// with a fitting type, see [`deinflect_with_lexicon`](crate::deinflect_with_lexicon).
+/// Words that are found as they are, without any deinflection, fit any of their types.
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::{scan_with_lexicon, RuleType};
+/// use std::collections::HashMap;
+///
+/// let lexicon = HashMap::from([
+///     ("映画".to_string(), vec![]),
+///     ("見る".to_string(), vec![RuleType::V1]),
+/// ]);
+/// let text = "昨日映画を見に行った";
+/// let found = scan_with_lexicon(text, text.find('映').unwrap(), &lexicon).unwrap();
+/// assert_eq!(&text[found.span], "映画");
+/// ```
+pub fn scan_with_lexicon(
+    text: &str,
+    start: usize,
+    lexicon: &impl Lexicon,
+) -> Option<ScanMatch<'static>> {
+    scan_with(text, start, |deinflection| {
+        is_in_lexicon(deinflection, lexicon)
+    })
+}
+
+/// Like [`scan_with`], but without a lexicon: returns the longest prefix that can be
+/// deinflected at all, together with all of its deinflections
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::scan;
+/// let text = "食べさせられた。";
+/// let found = scan(text, 0).unwrap();
+/// assert_eq!(&text[found.span], "食べさせられた");
+/// ```
+pub fn scan(text: &str, start: usize) -> Option<ScanMatch<'static>> {
+    scan_with(text, start, |deinflection| !deinflection.rules.is_empty())
+}
+
+/// Scans `text` with the given rules, see [`scan_with`].
+/// If `normalized` is set, each prefix is [`normalize`](crate::normalize)d before it is
+/// deinflected.
+pub(crate) fn scan_with_rules<'a>(
+    rules: &'a Rules,
+    normalized: bool,
+    text: &str,
+    start: usize,
+    mut is_known: impl FnMut(&Deinflection) -> bool,
+) -> Option<ScanMatch<'a>> {
+    let rest = text.get(start..)?;
+    let ends: Vec<usize> = rest
+        .char_indices()
+        .map(|(i, c)| i + c.len_utf8())
+        .take(MAX_SCAN_LENGTH)
+        .collect();
+
+    for &end in ends.iter().rev() {
+        let prefix = &rest[..end];
+        let (spellings, offsets) = if normalized {
+            let (spellings, offsets) = normalized_spellings(prefix);
+            (spellings, Some(offsets))
+        } else {
+            (vec![prefix.to_string()], None)
+        };
+        // The word as it is, so that dictionary forms containing ー, iteration marks or
+        // half-width kana are found in their own spelling as well
+        let mut uninflected: Vec<Deinflection> = [prefix.to_string(), kata_to_hira(prefix)]
+            .into_iter()
+            .map(|word| uninflected_word(word, prefix.len()))
+            .collect();
+        if let Some(offsets) = &offsets {
+            let folded = expand_iteration_marks(&fold_width(prefix));
+            uninflected.extend(iter::once(folded).chain(spellings.clone()).map(|word| {
+                let end = word.len();
+                offsets.to_original(uninflected_word(word, end))
+            }));
+        }
+        let mut seen = FxHashSet::default();
+        uninflected.retain(|deinflection| seen.insert(deinflection.word.clone()));
+        let deinflections: Vec<_> = uninflected
+            .into_iter()
+            .chain(
+                spellings
+                    .iter()
+                    .flat_map(|spelling| deinflect_with_rules(rules, spelling))
+                    .map(|deinflection| match &offsets {
+                        Some(offsets) => offsets.to_original(deinflection),
+                        None => deinflection,
+                    }),
+            )
+            .filter(|deinflection| is_known(deinflection))
+            .collect();
+        if !deinflections.is_empty() {
+            return Some(ScanMatch {
+                span: start..start + end,
+                deinflections,
+            });
+        }
+    }
+    None
+}
+
+/// Returns the deinflection of a word that is taken as it is, which ends at `end`
+fn uninflected_word<'a>(word: String, end: usize) -> Deinflection<'a> {
+    Deinflection {
+        word,
+        types: &[],
+        rules: Vec::new(),
+        inflection_span: end..end,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deinflect::RuleType;
+    use crate::deinflector::Deinflector;
+    use crate::inflection_kind::InflectionKind;
+    use std::collections::HashMap;
+
+    const TEXT: &str = "昨日映画を見に行ってしまったんだ";
+
+    fn lexicon() -> HashMap<String, Vec<RuleType>> {
+        [
+            ("昨日", vec![]),
+            ("映画", vec![]),
+            ("行く", vec![RuleType::V5KS]),
+            ("行う", vec![RuleType::V5U]),
+        ]
+        .into_iter()
+        .map(|(word, types)| (word.to_string(), types))
+        .collect()
+    }
+
+    fn scan_at(c: char) -> Option<ScanMatch<'static>> {
+        scan_with_lexicon(TEXT, TEXT.find(c).unwrap(), &lexicon())
+    }
+
+    #[test]
+    fn test_finds_longest_word() {
+        let found = scan_at('行').unwrap();
+        assert_eq!(&TEXT[found.span.clone()], "行ってしまった");
+        let words: Vec<&str> = found
+            .deinflections
+            .iter()
+            .map(|d| d.word.as_str())
+            .collect();
+        // 行う has the same te-form
+        assert_eq!(words, vec!["行く", "行う"]);
+        let iku = &found.deinflections[0];
+        assert_eq!(
+            iku.inflections().collect::<Vec<_>>(),
+            vec![
+                InflectionKind::Te,
+                InflectionKind::Shimau,
+                InflectionKind::Past
+            ]
+        );
+    }
+
+    #[test]
+    fn test_finds_uninflected_words() {
+        let found = scan_at('映').unwrap();
+        assert_eq!(&TEXT[found.span.clone()], "映画");
+        assert!(found.deinflections[0].rules.is_empty());
+
+        let found = scan_with_lexicon(TEXT, 0, &lexicon()).unwrap();
+        assert_eq!(found.span, 0..6);
+    }
+
+    #[test]
+    fn test_finds_words_with_normalized_characters() {
+        let lexicon = HashMap::from([
+            ("コーヒー".to_string(), vec![]),
+            ("いすず".to_string(), vec![]),
+        ]);
+        let is_known = |d: &Deinflection| is_in_lexicon(d, &lexicon);
+        let normalizing = Deinflector::new().with_normalization(true);
+
+        let text = "コーヒーを飲んだ";
+        for found in [
+            scan_with(text, 0, is_known),
+            normalizing.scan_with(text, 0, is_known),
+        ] {
+            let found = found.unwrap();
+            assert_eq!(&text[found.span], "コーヒー");
+            assert_eq!(found.deinflections[0].word, "コーヒー");
+        }
+
+        let text = "ｺｰﾋｰを飲んだ";
+        assert!(scan_with(text, 0, is_known).is_none());
+        let found = normalizing.scan_with(text, 0, is_known).unwrap();
+        assert_eq!(&text[found.span], "ｺｰﾋｰ");
+        assert_eq!(found.deinflections[0].word, "コーヒー");
+
+        let text = "いすゞに乗る";
+        let found = normalizing.scan_with(text, 0, is_known).unwrap();
+        assert_eq!(&text[found.span], "いすゞ");
+        assert_eq!(found.deinflections[0].word, "いすず");
+    }
+
+    #[test]
+    fn test_normalized_uninflected_words_refer_to_the_text() {
+        let deinflector = Deinflector::new().with_normalization(true);
+        for text in ["ｺｰﾋｰ", "いすゞ"] {
+            let found = deinflector
+                .scan_with(text, 0, |d| d.rules.is_empty())
+                .unwrap();
+            assert_eq!(found.span, 0..text.len());
+            assert!(found.deinflections.len() > 1);
+            for deinflection in &found.deinflections {
+                assert_eq!(deinflection.inflection_span, text.len()..text.len());
+                assert_eq!(deinflection.in_original_script(text), text);
+            }
+        }
+    }
+
+    #[test]
+    fn test_finds_masu_stems() {
+        let lexicon = HashMap::from([("読む".to_string(), vec![RuleType::V5M])]);
+        let found = scan_with_lexicon("本を読みに行く", 6, &lexicon).unwrap();
+        assert_eq!(found.span, 6..12);
+        assert_eq!(found.deinflections[0].word, "読む");
+    }
+
+    #[test]
+    fn test_finds_colloquial_contractions() {
+        let text = "そこに行っちゃ駄目";
+        let found = scan_with_lexicon(text, text.find('行').unwrap(), &lexicon()).unwrap();
+        assert_eq!(&text[found.span], "行っちゃ");
+        assert!(found.deinflections.iter().any(|d| d.word == "行く"));
+    }
+
+    #[test]
+    fn test_no_match() {
+        assert!(scan_at('を').is_none());
+        assert!(scan_with_lexicon(TEXT, TEXT.len(), &lexicon()).is_none());
+        // Not a character boundary
+        assert!(scan_with_lexicon(TEXT, 1, &lexicon()).is_none());
+    }
+
+    #[test]
+    fn test_without_lexicon() {
+        let found = scan(TEXT, TEXT.find('行').unwrap()).unwrap();
+        // Everything up to the end of the text ends in だ, which deinflects as a copula
+        assert!(found.span.end > TEXT.find('ん').unwrap());
+        assert!(found.deinflections.iter().all(|d| !d.rules.is_empty()));
+    }
+
+    #[test]
+    fn test_max_scan_length() {
+        let text = "あ".repeat(MAX_SCAN_LENGTH) + "食べた";
+        let max_bytes = 3 * MAX_SCAN_LENGTH;
+        assert!(scan(&text, 0).is_none_or(|found| found.span.end <= max_bytes));
+        assert_eq!(scan(&text, 9).unwrap().span.end, text.len());
+    }
+}
@@ -0,0 +1,49 @@
+//! JavaScript bindings, built with
+//! `cargo rustc --target wasm32-unknown-unknown --features wasm --crate-type cdylib`
+//! and `wasm-bindgen`, see the README
+
+use wasm_bindgen::prelude::*;
+
+/// Returns the possible base forms of `word`, see [`deinflect`](crate::deinflect)
+#[wasm_bindgen]
+pub fn deinflect(word: &str) -> Vec<String> {
+    crate::deinflect(word)
+}
+
+/// Returns the deinflections of `word` as an array of objects with the fields of
+/// [`Deinflection`](crate::Deinflection), e.g.
+/// `{ word: "待つ", types: ["V5T"], rules: [...], inflection_span: { start: 3, end: 9 } }`
+#[wasm_bindgen(js_name = deinflectDetailed)]
+pub fn deinflect_detailed(word: &str) -> JsValue {
+    serde_wasm_bindgen::to_value(&crate::deinflect_detailed(word))
+        .expect("deinflections only contain strings, numbers and arrays")
+}
+
+/// Converts the katakana in `kata` to hiragana, see [`kata_to_hira`](crate::kata_to_hira)
+#[wasm_bindgen(js_name = kataToHira)]
+pub fn kata_to_hira(kata: &str) -> String {
+    crate::kata_to_hira(kata)
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_bindings() {
+        assert!(deinflect("待った").contains(&"待つ".to_string()));
+        assert_eq!(kata_to_hira("マッタ"), "まった");
+
+        let deinflections = js_sys::Array::from(&deinflect_detailed("待った"));
+        let matsu = deinflections
+            .iter()
+            .find(|d| js_sys::Reflect::get(d, &"word".into()).unwrap() == "待つ")
+            .unwrap();
+        let types = js_sys::Reflect::get(&matsu, &"types".into()).unwrap();
+        assert_eq!(
+            js_sys::Array::from(&types).to_vec(),
+            vec![JsValue::from("V5T")]
+        );
+    }
+}
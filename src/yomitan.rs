@@ -0,0 +1,647 @@
+use crate::deinflect::RuleType;
+use crate::inflection_kind::InflectionKind;
+use crate::rule_set::{godan_types, OwnedDeinflectionRule, RuleSet, RuleSetError};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The result of [`import_yomitan_transforms`]
+#[derive(Debug, Clone)]
+pub struct YomitanImport {
+    /// The rules of all transforms that could be converted
+    pub rule_set: RuleSet,
+    /// The names of the transforms without a corresponding [`InflectionKind`], which were skipped
+    pub unknown_transforms: Vec<String>,
+    /// The number of rules that were skipped, because they aren't suffix rules or only
+    /// deinflect to intermediate conditions like "-te" that have no corresponding [`RuleType`]
+    pub skipped_rules: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Descriptor {
+    language: String,
+    conditions: BTreeMap<String, Condition>,
+    transforms: BTreeMap<String, Transform>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Condition {
+    name: String,
+    is_dictionary_form: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    sub_conditions: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Transform {
+    name: String,
+    #[serde(default)]
+    description: String,
+    rules: Vec<TransformRule>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransformRule {
+    #[serde(rename = "type")]
+    rule_type: String,
+    is_inflected: String,
+    deinflected: String,
+    conditions_in: Vec<String>,
+    conditions_out: Vec<String>,
+}
+
+/// The conditions of Yomitan's `japanese-transforms.js` as
+/// (name, description, is dictionary form, sub-conditions)
+const CONDITIONS: &[(&str, &str, bool, &[&str])] = &[
+    ("v", "Verb", false, &["v1", "v5", "vk", "vs", "vz"]),
+    ("v1", "Ichidan verb", true, &["v1d", "v1p"]),
+    ("v1d", "Ichidan verb, dictionary form", false, &[]),
+    (
+        "v1p",
+        "Ichidan verb, progressive or perfect form",
+        false,
+        &[],
+    ),
+    ("v5", "Godan verb", true, &["v5d", "v5s"]),
+    ("v5d", "Godan verb, dictionary form", false, &[]),
+    (
+        "v5s",
+        "Godan verb, short causative form",
+        false,
+        &["v5ss", "v5sp"],
+    ),
+    (
+        "v5ss",
+        "Godan verb, short causative form having さす ending (cannot conjugate with passive form)",
+        false,
+        &[],
+    ),
+    (
+        "v5sp",
+        "Godan verb, short causative form not having さす ending (can conjugate with passive form)",
+        false,
+        &[],
+    ),
+    ("vk", "Kuru verb", true, &[]),
+    ("vs", "Suru verb", true, &[]),
+    ("vz", "Zuru verb", true, &[]),
+    ("adj-i", "Adjective with i ending", true, &[]),
+    ("-ます", "Polite -ます ending", false, &[]),
+    ("-ません", "Polite negative -ません ending", false, &[]),
+    (
+        "-te",
+        "Intermediate -te endings for progressive or perfect tense",
+        false,
+        &[],
+    ),
+    (
+        "-ba",
+        "Intermediate -ba endings for conditional contraction",
+        false,
+        &[],
+    ),
+    ("adv", "Intermediate -ku endings for adverbs", false, &[]),
+    ("past", "-ta past form ending", false, &[]),
+];
+
+/// The name of the Yomitan condition corresponding to `rule_type` in a rule of the given kind,
+/// `None` if Yomitan has no such condition, like for na-adjectives or the classical types.
+/// Yomitan doesn't distinguish the godan classes, except that only the dictionary form of a
+/// godan verb has a potential.
+fn condition_name(rule_type: RuleType, kind: InflectionKind) -> Option<&'static str> {
+    let name = match rule_type {
+        RuleType::AdjI => "adj-i",
+        RuleType::Iru => "-te",
+        RuleType::V1 => "v1",
+        _ if rule_type.is_godan() && kind == InflectionKind::Potential => "v5d",
+        _ if rule_type.is_godan() => "v5",
+        RuleType::Vk => "vk",
+        RuleType::Vs => "vs",
+        RuleType::Vz => "vz",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Converts the Yomitan conditions into rule types.
+/// Yomitan only distinguishes some godan forms, so its godan conditions are narrowed down using
+/// the ending of the verb. Conditions without a counterpart are expanded into their
+/// sub-conditions, or dropped if they have none.
+fn rule_types(
+    names: &[String],
+    kana: &str,
+    conditions: &BTreeMap<String, Condition>,
+    types: &mut Vec<RuleType>,
+) {
+    for name in names {
+        let new_types = match name.as_str() {
+            // Yomitan's godan verb, godan dictionary form and short causative conditions
+            "v5" | "v5d" | "v5s" | "v5ss" | "v5sp" => godan_types(kana),
+            "v1d" | "v1p" => vec![RuleType::V1],
+            "vs" => vec![RuleType::Vs],
+            // The te-form that いる and other auxiliaries attach to
+            "-te" => vec![RuleType::Iru],
+            _ => match RuleType::from_pos_tag(name) {
+                Some(rule_type) => vec![rule_type],
+                None => {
+                    if let Some(condition) = conditions.get(name) {
+                        rule_types(&condition.sub_conditions, kana, conditions, types);
+                    }
+                    continue;
+                }
+            },
+        };
+        for rule_type in new_types {
+            if !types.contains(&rule_type) {
+                types.push(rule_type);
+            }
+        }
+    }
+}
+
+/// Parses the language transforms of Yomitan (the JSON equivalent of its
+/// `japanese-transforms.js`) into a [`RuleSet`].
+/// The transforms are matched with [`InflectionKind`]s by their English names.
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::{import_yomitan_transforms, Deinflector};
+/// let json = r#"{
+///     "language": "ja",
+///     "conditions": {
+///         "v5": { "name": "Godan verb", "isDictionaryForm": true }
+///     },
+///     "transforms": {
+///         "past": {
+///             "name": "past",
+///             "rules": [{
+///                 "type": "suffix", "isInflected": "った", "deinflected": "つ",
+///                 "conditionsIn": ["past"], "conditionsOut": ["v5"]
+///             }]
+///         }
+///     }
+/// }"#;
+/// let import = import_yomitan_transforms(json).unwrap();
+/// let deinflector = Deinflector::with_rules(import.rule_set);
+/// assert_eq!(deinflector.deinflect("待った"), vec!["待つ"]);
+/// ```
+pub fn import_yomitan_transforms(json: &str) -> Result<YomitanImport, RuleSetError> {
+    let descriptor: Descriptor = serde_json::from_str(json)?;
+
+    let mut import = YomitanImport {
+        rule_set: RuleSet::new(),
+        unknown_transforms: Vec::new(),
+        skipped_rules: 0,
+    };
+    for (key, transform) in &descriptor.transforms {
+        let Some(kind) = InflectionKind::from_english_name(&transform.name)
+            .or_else(|| InflectionKind::from_english_name(key))
+        else {
+            import.unknown_transforms.push(transform.name.clone());
+            continue;
+        };
+        for rule in &transform.rules {
+            // Older exports store the suffix as a regular expression
+            let kana_in = rule
+                .is_inflected
+                .strip_suffix('$')
+                .unwrap_or(&rule.is_inflected);
+
+            let mut rules_out = Vec::new();
+            rule_types(
+                &rule.conditions_out,
+                &rule.deinflected,
+                &descriptor.conditions,
+                &mut rules_out,
+            );
+            if rule.rule_type != "suffix" || rules_out.is_empty() {
+                import.skipped_rules += 1;
+                continue;
+            }
+            let mut rules_in = Vec::new();
+            rule_types(
+                &rule.conditions_in,
+                kana_in,
+                &descriptor.conditions,
+                &mut rules_in,
+            );
+
+            import.rule_set.insert(
+                kana_in,
+                OwnedDeinflectionRule::new(rule.deinflected.clone(), rules_in, rules_out, kind),
+            );
+        }
+    }
+    Ok(import)
+}
+
+/// Exports `rule_set` in the language transform format of Yomitan.
+/// Each [`InflectionKind`] becomes a transform and the [`RuleType`]s are mapped to Yomitan's
+/// conditions, e.g. all godan classes to "v5". Rules for types that Yomitan has no condition
+/// for, like na-adjectives, the copula or the classical verbs, are left out.
+/// The output is sorted, so that it can be diffed.
+///
+/// # Examples
+/// ```
+/// use jp_deinflector::{export_yomitan_transforms, RuleSet};
+/// let json = export_yomitan_transforms(&RuleSet::builtin());
+/// assert!(json.contains(r#""isInflected": "かった""#));
+/// ```
+pub fn export_yomitan_transforms(rule_set: &RuleSet) -> String {
+    let conditions = CONDITIONS
+        .iter()
+        .map(|&(name, description, is_dictionary_form, sub_conditions)| {
+            let condition = Condition {
+                name: description.to_string(),
+                is_dictionary_form,
+                sub_conditions: sub_conditions.iter().map(|s| s.to_string()).collect(),
+            };
+            (name.to_string(), condition)
+        })
+        .collect();
+
+    let mut transforms: BTreeMap<String, Transform> = BTreeMap::new();
+    for (kana_in, rule) in rule_set.iter() {
+        let names = |types: &[RuleType]| {
+            let mut names: Vec<String> = Vec::new();
+            for name in types.iter().filter_map(|&t| condition_name(t, rule.kind)) {
+                if !names.iter().any(|n| n == name) {
+                    names.push(name.to_string());
+                }
+            }
+            names
+        };
+        let conditions_in = names(&rule.rules_in);
+        let conditions_out = names(&rule.rules_out);
+        // Without conditions, the rule would apply to anything
+        if conditions_out.is_empty() || (conditions_in.is_empty() && !rule.rules_in.is_empty()) {
+            continue;
+        }
+
+        let name = rule.kind.english_name();
+        let transform = transforms
+            .entry(name.to_string())
+            .or_insert_with(|| Transform {
+                name: name.to_string(),
+                description: rule.kind.japanese_name().to_string(),
+                rules: Vec::new(),
+            });
+        transform.rules.push(TransformRule {
+            rule_type: "suffix".to_string(),
+            is_inflected: kana_in.to_string(),
+            deinflected: rule.kana_out.clone(),
+            conditions_in,
+            conditions_out,
+        });
+    }
+    for transform in transforms.values_mut() {
+        let key = |rule: &TransformRule| {
+            (
+                rule.is_inflected.clone(),
+                rule.deinflected.clone(),
+                rule.conditions_out.clone(),
+                rule.conditions_in.clone(),
+            )
+        };
+        transform.rules.sort_by_key(key);
+        // Rules for godan classes that Yomitan doesn't distinguish become identical
+        transform.rules.dedup_by_key(|rule| key(rule));
+    }
+
+    let descriptor = Descriptor {
+        language: "ja".to_string(),
+        conditions,
+        transforms,
+    };
+    serde_json::to_string_pretty(&descriptor).expect("the descriptor only contains strings")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deinflector::Deinflector;
+
+    const TRANSFORMS: &str = r#"{
+        "language": "ja",
+        "conditions": {
+            "v": { "name": "Verb", "isDictionaryForm": false, "subConditions": ["v1", "v5", "vk"] },
+            "v1": { "name": "Ichidan verb", "isDictionaryForm": true, "subConditions": ["v1d", "v1p"] },
+            "v5": { "name": "Godan verb", "isDictionaryForm": true, "subConditions": ["v5d", "v5s"] },
+            "vk": { "name": "Kuru verb", "isDictionaryForm": true },
+            "adj-i": { "name": "Adjective with i ending", "isDictionaryForm": true },
+            "-te": { "name": "Intermediate -te endings", "isDictionaryForm": false },
+            "-nai": { "name": "Negative with ない", "isDictionaryForm": false }
+        },
+        "transforms": {
+            "negative": {
+                "name": "negative",
+                "description": "1. Negative form of verbs.",
+                "rules": [
+                    { "type": "suffix", "isInflected": "ない", "deinflected": "る", "conditionsIn": ["adj-i"], "conditionsOut": ["v1"] },
+                    { "type": "suffix", "isInflected": "かない", "deinflected": "く", "conditionsIn": ["adj-i"], "conditionsOut": ["v5"] }
+                ]
+            },
+            "-te": {
+                "name": "-te",
+                "rules": [
+                    { "type": "suffix", "isInflected": "て", "deinflected": "る", "conditionsIn": ["-te"], "conditionsOut": ["v1"] },
+                    { "type": "suffix", "isInflected": "ないで", "deinflected": "ない", "conditionsIn": ["-te"], "conditionsOut": ["-nai"] }
+                ]
+            },
+            "-ya": {
+                "name": "-ya",
+                "rules": [
+                    { "type": "suffix", "isInflected": "けりゃ", "deinflected": "ければ", "conditionsIn": [], "conditionsOut": ["-ba"] }
+                ]
+            },
+            "potential": {
+                "name": "potential",
+                "rules": [
+                    { "type": "suffix", "isInflected": "れる", "deinflected": "る", "conditionsIn": ["v1"], "conditionsOut": ["v"] }
+                ]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_import() {
+        let import = import_yomitan_transforms(TRANSFORMS).unwrap();
+        assert_eq!(import.unknown_transforms, vec!["-ya"]);
+        // ないで only deinflects to the intermediate -nai condition
+        assert_eq!(import.skipped_rules, 1);
+        assert_eq!(import.rule_set.len(), 4);
+
+        let kanai = &import.rule_set.get("かない").unwrap()[0];
+        assert_eq!(kanai.rules_in, vec![RuleType::AdjI]);
+        assert_eq!(kanai.rules_out, vec![RuleType::V5K]);
+        assert_eq!(kanai.kind, InflectionKind::Negative);
+
+        // The sub-conditions of "v" are expanded
+        let reru = &import.rule_set.get("れる").unwrap()[0];
+        assert_eq!(
+            reru.rules_out,
+            vec![RuleType::V1, RuleType::V5R, RuleType::Vk]
+        );
+
+        let deinflector = Deinflector::with_rules(import.rule_set);
+        assert!(deinflector
+            .deinflect("書かない")
+            .iter()
+            .any(|w| w == "書く"));
+        assert!(deinflector
+            .deinflect("食べて")
+            .iter()
+            .any(|w| w == "食べる"));
+    }
+
+    /// An excerpt of the JSON form of Yomitan's `japanese-transforms.js`: all of its conditions
+    /// and some of its transforms, with their rules as upstream defines them
+    const UPSTREAM_TRANSFORMS: &str = r#"{
+        "language": "ja",
+        "conditions": {
+            "v": { "name": "Verb", "isDictionaryForm": false, "subConditions": ["v1", "v5", "vk", "vs", "vz"] },
+            "v1": { "name": "Ichidan verb", "isDictionaryForm": true, "subConditions": ["v1d", "v1p"] },
+            "v1d": { "name": "Ichidan verb, dictionary form", "isDictionaryForm": false },
+            "v1p": { "name": "Ichidan verb, progressive or perfect form", "isDictionaryForm": false },
+            "v5": { "name": "Godan verb", "isDictionaryForm": true, "subConditions": ["v5d", "v5s"] },
+            "v5d": { "name": "Godan verb, dictionary form", "isDictionaryForm": false },
+            "v5s": { "name": "Godan verb, short causative form", "isDictionaryForm": false, "subConditions": ["v5ss", "v5sp"] },
+            "v5ss": { "name": "Godan verb, short causative form having さす ending (cannot conjugate with passive form)", "isDictionaryForm": false },
+            "v5sp": { "name": "Godan verb, short causative form not having さす ending (can conjugate with passive form)", "isDictionaryForm": false },
+            "vk": { "name": "Kuru verb", "isDictionaryForm": true },
+            "vs": { "name": "Suru verb", "isDictionaryForm": true },
+            "vz": { "name": "Zuru verb", "isDictionaryForm": true },
+            "adj-i": { "name": "Adjective with i ending", "isDictionaryForm": true },
+            "-ます": { "name": "Polite -ます ending", "isDictionaryForm": false },
+            "-ません": { "name": "Polite negative -ません ending", "isDictionaryForm": false },
+            "-te": { "name": "Intermediate -te endings for progressive or perfect tense", "isDictionaryForm": false },
+            "-ba": { "name": "Intermediate -ba endings for conditional contraction", "isDictionaryForm": false },
+            "adv": { "name": "Intermediate -ku endings for adverbs", "isDictionaryForm": false },
+            "past": { "name": "-ta past form ending", "isDictionaryForm": false }
+        },
+        "transforms": {
+            "past": {
+                "name": "past",
+                "description": "-ta past form",
+                "rules": [
+                    { "type": "suffix", "isInflected": "た", "deinflected": "る", "conditionsIn": ["past"], "conditionsOut": ["v1"] },
+                    { "type": "suffix", "isInflected": "いた", "deinflected": "く", "conditionsIn": ["past"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "いだ", "deinflected": "ぐ", "conditionsIn": ["past"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "した", "deinflected": "す", "conditionsIn": ["past"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "った", "deinflected": "う", "conditionsIn": ["past"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "った", "deinflected": "つ", "conditionsIn": ["past"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "った", "deinflected": "る", "conditionsIn": ["past"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "んだ", "deinflected": "ぬ", "conditionsIn": ["past"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "んだ", "deinflected": "ぶ", "conditionsIn": ["past"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "んだ", "deinflected": "む", "conditionsIn": ["past"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "かった", "deinflected": "い", "conditionsIn": ["past"], "conditionsOut": ["adj-i"] },
+                    { "type": "suffix", "isInflected": "した", "deinflected": "する", "conditionsIn": ["past"], "conditionsOut": ["vs"] },
+                    { "type": "suffix", "isInflected": "きた", "deinflected": "くる", "conditionsIn": ["past"], "conditionsOut": ["vk"] }
+                ]
+            },
+            "-te": {
+                "name": "-te",
+                "description": "te-form",
+                "rules": [
+                    { "type": "suffix", "isInflected": "て", "deinflected": "る", "conditionsIn": ["-te"], "conditionsOut": ["v1"] },
+                    { "type": "suffix", "isInflected": "いて", "deinflected": "く", "conditionsIn": ["-te"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "いで", "deinflected": "ぐ", "conditionsIn": ["-te"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "して", "deinflected": "す", "conditionsIn": ["-te"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "って", "deinflected": "う", "conditionsIn": ["-te"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "って", "deinflected": "つ", "conditionsIn": ["-te"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "って", "deinflected": "る", "conditionsIn": ["-te"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "んで", "deinflected": "ぬ", "conditionsIn": ["-te"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "んで", "deinflected": "ぶ", "conditionsIn": ["-te"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "んで", "deinflected": "む", "conditionsIn": ["-te"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "くて", "deinflected": "い", "conditionsIn": ["-te"], "conditionsOut": ["adj-i"] },
+                    { "type": "suffix", "isInflected": "して", "deinflected": "する", "conditionsIn": ["-te"], "conditionsOut": ["vs"] },
+                    { "type": "suffix", "isInflected": "きて", "deinflected": "くる", "conditionsIn": ["-te"], "conditionsOut": ["vk"] }
+                ]
+            },
+            "progressive or perfect": {
+                "name": "progressive or perfect",
+                "description": "Progressive or perfect form",
+                "rules": [
+                    { "type": "suffix", "isInflected": "ている", "deinflected": "て", "conditionsIn": ["v1"], "conditionsOut": ["-te"] },
+                    { "type": "suffix", "isInflected": "ておる", "deinflected": "て", "conditionsIn": ["v5"], "conditionsOut": ["-te"] },
+                    { "type": "suffix", "isInflected": "てる", "deinflected": "て", "conditionsIn": ["v1p"], "conditionsOut": ["-te"] },
+                    { "type": "suffix", "isInflected": "でいる", "deinflected": "で", "conditionsIn": ["v1"], "conditionsOut": ["-te"] },
+                    { "type": "suffix", "isInflected": "でおる", "deinflected": "で", "conditionsIn": ["v5"], "conditionsOut": ["-te"] },
+                    { "type": "suffix", "isInflected": "でる", "deinflected": "で", "conditionsIn": ["v1p"], "conditionsOut": ["-te"] }
+                ]
+            },
+            "negative": {
+                "name": "negative",
+                "description": "Negative form",
+                "rules": [
+                    { "type": "suffix", "isInflected": "くない", "deinflected": "い", "conditionsIn": ["adj-i"], "conditionsOut": ["adj-i"] },
+                    { "type": "suffix", "isInflected": "ない", "deinflected": "る", "conditionsIn": ["adj-i"], "conditionsOut": ["v1"] },
+                    { "type": "suffix", "isInflected": "かない", "deinflected": "く", "conditionsIn": ["adj-i"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "がない", "deinflected": "ぐ", "conditionsIn": ["adj-i"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "さない", "deinflected": "す", "conditionsIn": ["adj-i"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "たない", "deinflected": "つ", "conditionsIn": ["adj-i"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "なない", "deinflected": "ぬ", "conditionsIn": ["adj-i"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "ばない", "deinflected": "ぶ", "conditionsIn": ["adj-i"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "まない", "deinflected": "む", "conditionsIn": ["adj-i"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "らない", "deinflected": "る", "conditionsIn": ["adj-i"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "わない", "deinflected": "う", "conditionsIn": ["adj-i"], "conditionsOut": ["v5"] },
+                    { "type": "suffix", "isInflected": "しない", "deinflected": "する", "conditionsIn": ["adj-i"], "conditionsOut": ["vs"] },
+                    { "type": "suffix", "isInflected": "こない", "deinflected": "くる", "conditionsIn": ["adj-i"], "conditionsOut": ["vk"] }
+                ]
+            },
+            "potential": {
+                "name": "potential",
+                "description": "Potential form",
+                "rules": [
+                    { "type": "suffix", "isInflected": "れる", "deinflected": "る", "conditionsIn": ["v1"], "conditionsOut": ["v1", "v5d"] },
+                    { "type": "suffix", "isInflected": "える", "deinflected": "う", "conditionsIn": ["v1"], "conditionsOut": ["v5d"] },
+                    { "type": "suffix", "isInflected": "ける", "deinflected": "く", "conditionsIn": ["v1"], "conditionsOut": ["v5d"] },
+                    { "type": "suffix", "isInflected": "げる", "deinflected": "ぐ", "conditionsIn": ["v1"], "conditionsOut": ["v5d"] },
+                    { "type": "suffix", "isInflected": "せる", "deinflected": "す", "conditionsIn": ["v1"], "conditionsOut": ["v5d"] },
+                    { "type": "suffix", "isInflected": "てる", "deinflected": "つ", "conditionsIn": ["v1"], "conditionsOut": ["v5d"] },
+                    { "type": "suffix", "isInflected": "ねる", "deinflected": "ぬ", "conditionsIn": ["v1"], "conditionsOut": ["v5d"] },
+                    { "type": "suffix", "isInflected": "べる", "deinflected": "ぶ", "conditionsIn": ["v1"], "conditionsOut": ["v5d"] },
+                    { "type": "suffix", "isInflected": "める", "deinflected": "む", "conditionsIn": ["v1"], "conditionsOut": ["v5d"] },
+                    { "type": "suffix", "isInflected": "できる", "deinflected": "する", "conditionsIn": ["v1"], "conditionsOut": ["vs"] },
+                    { "type": "suffix", "isInflected": "これる", "deinflected": "くる", "conditionsIn": ["v1"], "conditionsOut": ["vk"] }
+                ]
+            }
+        }
+    }"#;
+
+    fn sorted(rule_set: &RuleSet) -> Vec<(String, OwnedDeinflectionRule)> {
+        let mut rules: Vec<_> = rule_set
+            .iter()
+            .map(|(kana_in, rule)| (kana_in.to_string(), rule.clone()))
+            .collect();
+        rules.sort_by(|a, b| format!("{a:?}").cmp(&format!("{b:?}")));
+        rules
+    }
+
+    /// Returns the names of all conditions that the rules of `descriptor` use
+    fn used_conditions(descriptor: &Descriptor) -> Vec<&str> {
+        descriptor
+            .transforms
+            .values()
+            .flat_map(|transform| &transform.rules)
+            .flat_map(|rule| rule.conditions_in.iter().chain(&rule.conditions_out))
+            .map(String::as_str)
+            .collect()
+    }
+
+    #[test]
+    fn test_export() {
+        let json = export_yomitan_transforms(&RuleSet::builtin());
+        let descriptor: Descriptor = serde_json::from_str(&json).unwrap();
+        assert_eq!(descriptor.language, "ja");
+        let upstream: Descriptor = serde_json::from_str(UPSTREAM_TRANSFORMS).unwrap();
+        assert_eq!(
+            descriptor.conditions.keys().collect::<Vec<_>>(),
+            upstream.conditions.keys().collect::<Vec<_>>()
+        );
+        for name in used_conditions(&descriptor) {
+            assert!(
+                upstream.conditions.contains_key(name),
+                "Unknown condition {name}"
+            );
+        }
+
+        // Godan verbs are exported as v5, whatever their ending
+        let past = &descriptor.transforms["past"];
+        for (is_inflected, deinflected) in [("した", "す"), ("った", "つ"), ("った", "う")]
+        {
+            assert!(past
+                .rules
+                .iter()
+                .any(|rule| rule.is_inflected == is_inflected
+                    && rule.deinflected == deinflected
+                    && rule.conditions_out == ["v5"]));
+        }
+        assert!(descriptor.transforms["potential"]
+            .rules
+            .iter()
+            .any(|rule| rule.is_inflected == "ける" && rule.conditions_out == ["v5d"]));
+        assert!(past
+            .rules
+            .windows(2)
+            .all(|w| w[0].is_inflected <= w[1].is_inflected));
+    }
+
+    #[test]
+    fn test_upstream_roundtrip() {
+        let import = import_yomitan_transforms(UPSTREAM_TRANSFORMS).unwrap();
+        assert!(import.unknown_transforms.is_empty());
+        assert_eq!(import.skipped_rules, 0);
+        let deinflector = Deinflector::with_rules(import.rule_set.clone());
+        for (word, expected) in [
+            ("書かない", "書く"),
+            ("書ける", "書く"),
+            ("泳いだ", "泳ぐ"),
+            ("食べている", "食べる"),
+            ("読んでる", "読む"),
+            ("高かった", "高い"),
+            ("勉強しない", "勉強する"),
+        ] {
+            assert!(
+                deinflector.deinflect(word).iter().any(|w| w == expected),
+                "'{word}' did not deinflect to '{expected}'"
+            );
+        }
+
+        // Exporting the imported rules gives back the upstream conditions and rules
+        let json = export_yomitan_transforms(&import.rule_set);
+        let descriptor: Descriptor = serde_json::from_str(&json).unwrap();
+        let upstream: Descriptor = serde_json::from_str(UPSTREAM_TRANSFORMS).unwrap();
+        assert_eq!(
+            descriptor.conditions.keys().collect::<Vec<_>>(),
+            upstream.conditions.keys().collect::<Vec<_>>()
+        );
+        for (key, transform) in &upstream.transforms {
+            let exported = &descriptor.transforms[&transform.name];
+            for rule in &transform.rules {
+                assert!(
+                    exported.rules.iter().any(|exported| {
+                        exported.is_inflected == rule.is_inflected
+                            && exported.deinflected == rule.deinflected
+                    }),
+                    "{key}: {} -> {} is missing",
+                    rule.is_inflected,
+                    rule.deinflected
+                );
+            }
+        }
+
+        let reimport = import_yomitan_transforms(&json).unwrap();
+        assert_eq!(sorted(&reimport.rule_set), sorted(&import.rule_set));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let json = export_yomitan_transforms(&RuleSet::builtin());
+        let import = import_yomitan_transforms(&json).unwrap();
+        assert!(import.unknown_transforms.is_empty());
+        assert_eq!(import.skipped_rules, 0);
+
+        // The godan classes are narrowed down again by the ending of the verb
+        let kanai = &import.rule_set.get("かない").unwrap()[0];
+        assert_eq!(kanai.rules_out, vec![RuleType::V5K]);
+
+        let deinflector = Deinflector::with_rules(import.rule_set);
+        for (word, expected) in [
+            ("食べさせられなかった", "食べる"),
+            ("書かない", "書く"),
+            ("泳いでいる", "泳ぐ"),
+            ("話した", "話す"),
+            ("高くなかった", "高い"),
+            ("来させられる", "来る"),
+            ("問うた", "問う"),
+        ] {
+            assert!(
+                deinflector.deinflect(word).iter().any(|w| w == expected),
+                "'{word}' did not deinflect to '{expected}'"
+            );
+        }
+    }
+}